@@ -0,0 +1,237 @@
+// Python bindings for reading saves straight into notebooks.
+//
+//     import ehm_save, pandas
+//     save = ehm_save.load_save("game.sav")
+//     players = pandas.DataFrame(save.player_columns(["Name", "Age", "Current Ability", "Passing"]))
+
+use std::{path::Path, sync::atomic::AtomicBool};
+
+use pyo3::{
+    IntoPyObjectExt as _, exceptions::{PyKeyError, PyOSError, PyValueError}, prelude::*, types::{PyDict, PyList},
+};
+use serde_json::Value;
+
+use ehm_save::{
+    data::{Data, player::CONVERTED_ATTRIBUTES, try_convert_attribute}, editor, filters::PlayerFilters,
+    init::{LoadError, load_database_folder, try_load_bin}, scripts::Scripts, views,
+};
+
+// The player columns if none are asked for.
+const DEFAULT_COLUMNS: [&str; 15] = [
+    "Name", "Nation", "Club Contracted", "Club Playing", "Age", "Birthday", "Position", "Current Ability",
+    "Potential Ability", "GK Rating", "LD Rating", "RD Rating", "LW Rating", "C Rating", "RW Rating",
+];
+
+#[pyclass(name = "Save", frozen)]
+struct Save {
+    data: Data,
+}
+
+#[pymethods]
+impl Save {
+    // Get the players that match the filters as one dict per player. The columns are the ones of the viewer, and
+    // the filters are the same dict the viewer sends.
+    #[pyo3(signature = (columns=None, filters=None, display_currency=None))]
+    fn players<'py>(
+        &self,
+        py: Python<'py>,
+        columns: Option<Vec<String>>,
+        filters: Option<&Bound<'py, PyDict>>,
+        display_currency: Option<i32>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let (columns, players) = self.player_views(py, columns, filters, display_currency)?;
+
+        return players.into_iter().map(|player| {
+            let dict = PyDict::new(py);
+            dict.set_item("Staff ID", player.id)?;
+            dict.set_item("Forename", player.forename)?;
+            dict.set_item("Surname", player.surname)?;
+            for (column, value) in columns.iter().zip(player.columns.iter()) {
+                dict.set_item(column, to_py(py, value)?)?;
+            }
+
+            Ok(dict)
+        }).collect();
+    }
+
+    // Get the players that match the filters as a list of values per column, ready for pandas or Arrow.
+    #[pyo3(signature = (columns=None, filters=None, display_currency=None))]
+    fn player_columns<'py>(
+        &self,
+        py: Python<'py>,
+        columns: Option<Vec<String>>,
+        filters: Option<&Bound<'py, PyDict>>,
+        display_currency: Option<i32>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let (columns, players) = self.player_views(py, columns, filters, display_currency)?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("Staff ID", players.iter().map(|player| player.id).collect::<Vec<i32>>())?;
+        dict.set_item("Forename", players.iter().map(|player| player.forename.as_str()).collect::<Vec<&str>>())?;
+        dict.set_item("Surname", players.iter().map(|player| player.surname.as_str()).collect::<Vec<&str>>())?;
+        for (i, column) in columns.iter().enumerate() {
+            let values = PyList::empty(py);
+            for player in players.iter() {
+                values.append(to_py(py, &player.columns[i])?)?;
+            }
+
+            dict.set_item(column, values)?;
+        }
+
+        return Ok(dict);
+    }
+
+    // Get everything about one player. None if the person is not a player.
+    #[pyo3(signature = (staff_id, display_currency=None))]
+    fn player<'py>(&self, py: Python<'py>, staff_id: i32, display_currency: Option<i32>) -> PyResult<Bound<'py, PyAny>> {
+        return match views::player_detail(&self.data, staff_id, display_currency) {
+            Some(detail) => to_py(py, &serde_json::to_value(detail).map_err(|e| PyValueError::new_err(e.to_string()))?),
+            None => Ok(py.None().into_bound(py)),
+        };
+    }
+
+    // Get the position ratings of a player.
+    fn ratings<'py>(&self, py: Python<'py>, staff_id: i32) -> PyResult<Bound<'py, PyDict>> {
+        let person = match self.data.staff.get(&staff_id) {
+            Some(p) if p.player_data(&self.data).is_some() => p,
+            _ => return Err(PyKeyError::new_err(format!("There is no player with the ID {staff_id}"))),
+        };
+
+        let dict = PyDict::new(py);
+        dict.set_item("GK", person.gk_rating(&self.data))?;
+        dict.set_item("LD", person.ld_rating(&self.data))?;
+        dict.set_item("RD", person.rd_rating(&self.data))?;
+        dict.set_item("LW", person.lw_rating(&self.data))?;
+        dict.set_item("C", person.c_rating(&self.data))?;
+        dict.set_item("RW", person.rw_rating(&self.data))?;
+        return Ok(dict);
+    }
+
+    fn clubs<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        return self.entities(py, "club", self.data.clubs.keys());
+    }
+
+    fn nations<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        return self.entities(py, "nation", self.data.nations.keys());
+    }
+
+    fn competitions<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        return self.entities(py, "competition", self.data.competitions.keys());
+    }
+
+    // Get the possible ingame dates as day.month.year.
+    fn ingame_dates(&self) -> [String; 2] {
+        return views::ingame_dates(&self.data);
+    }
+}
+
+impl Save {
+    fn player_views(
+        &self,
+        py: Python<'_>,
+        columns: Option<Vec<String>>,
+        filters: Option<&Bound<'_, PyDict>>,
+        display_currency: Option<i32>,
+    ) -> PyResult<(Vec<String>, Vec<views::player::Player>)> {
+        let columns = columns.unwrap_or_else(|| {
+            DEFAULT_COLUMNS.iter().chain(CONVERTED_ATTRIBUTES.iter()).map(|c| c.to_string()).collect()
+        });
+
+        let filters: PlayerFilters = match filters {
+            Some(f) => {
+                let json: String = py.import("json")?.call_method1("dumps", (f,))?.extract()?;
+                serde_json::from_str(&json).map_err(|e| PyValueError::new_err(format!("filters: {e}")))?
+            }
+            None => PlayerFilters::default(),
+        };
+
+        let players = views::players(&self.data, &columns, &filters, display_currency, &Scripts::default());
+        return Ok((columns, players));
+    }
+
+    // Get the ID and text fields of every entity of a kind, sorted by ID.
+    fn entities<'py, 'a>(
+        &self,
+        py: Python<'py>,
+        kind: &str,
+        ids: impl Iterator<Item = &'a i32>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let mut ids: Vec<i32> = ids.copied().collect();
+        ids.sort();
+
+        return ids.into_iter().map(|id| {
+            let dict = PyDict::new(py);
+            dict.set_item("ID", id)?;
+            for field in editor::text_fields(&self.data, kind, id).map_err(|e| PyKeyError::new_err(e.to_string()))? {
+                dict.set_item(field.field, field.text)?;
+            }
+
+            Ok(dict)
+        }).collect();
+    }
+}
+
+// Load a save file, or a database if the path is a folder.
+#[pyfunction]
+fn load_save(path: &str) -> PyResult<Save> {
+    let path = Path::new(path);
+    let data = match path.is_dir() {
+        true => load_database_folder(path).map_err(load_error)?,
+        false if path.is_file() => try_load_bin(path, &AtomicBool::new(false), |_| {}).map_err(load_error)?,
+        false => return Err(PyOSError::new_err(format!("{} does not exist", path.display()))),
+    };
+
+    return Ok(Save { data });
+}
+
+// Files that can not be read are OSErrors, and files that are not saves are ValueErrors.
+fn load_error(e: LoadError) -> PyErr {
+    return match e {
+        LoadError::Io(..) => PyOSError::new_err(e.to_string()),
+        _ => PyValueError::new_err(e.to_string()),
+    };
+}
+
+// Convert an attribute from save file to in-game. None if the attribute chart has no range for it.
+#[pyfunction]
+fn convert_attribute(current_ability: i16, attribute: i8) -> Option<i8> {
+    return try_convert_attribute(current_ability, attribute);
+}
+
+fn to_py<'py>(py: Python<'py>, value: &Value) -> PyResult<Bound<'py, PyAny>> {
+    return match value {
+        Value::Null => Ok(py.None().into_bound(py)),
+        Value::Bool(b) => b.into_bound_py_any(py),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into_bound_py_any(py),
+            None => n.as_f64().into_bound_py_any(py),
+        },
+        Value::String(s) => s.into_bound_py_any(py),
+        Value::Array(values) => {
+            let list = PyList::empty(py);
+            for v in values {
+                list.append(to_py(py, v)?)?;
+            }
+
+            Ok(list.into_any())
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (k, v) in map {
+                dict.set_item(k, to_py(py, v)?)?;
+            }
+
+            Ok(dict.into_any())
+        }
+    };
+}
+
+#[pymodule]
+#[pyo3(name = "ehm_save")]
+fn ehm_save_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Save>()?;
+    m.add_function(wrap_pyfunction!(load_save, m)?)?;
+    m.add_function(wrap_pyfunction!(convert_attribute, m)?)?;
+    m.add("CONVERTED_ATTRIBUTES", CONVERTED_ATTRIBUTES.to_vec())?;
+    return Ok(());
+}
@@ -0,0 +1,144 @@
+// Character conversion.
+
+use std::{collections::HashMap, fmt};
+
+use lazy_static::lazy_static;
+
+lazy_static! {
+    // The characters SI's code page has in place of the Windows-1252 ones.
+    static ref SI_OVERRIDES: HashMap<u8, char> = HashMap::from([
+        (138, '\u{8A}'),  // Š is at 189 instead.
+        (143, 'ň'),
+        (144, 'ě'),
+        (146, '□'),  // Unknown character.
+        (157, 'ť'),
+        (159, 'ů'),
+        (160, 'č'),
+        (167, 'ř'),
+        (179, 'Ř'),
+        (187, 'ď'),
+        (188, 'Č'),
+        (189, 'Š'),
+        (190, 'Ď'),
+    ]);
+
+    // Every byte of the code page as a character.
+    static ref SI_TO_CHAR: [char; 256] = {
+        let mut chars = ['\0'; 256];
+        for (byte, c) in chars.iter_mut().enumerate() {
+            *c = match SI_OVERRIDES.get(&(byte as u8)) {
+                Some(c) => *c,
+                None => windows_1252_to_char(byte as u8),
+            };
+        }
+
+        chars
+    };
+
+    static ref CHAR_TO_SI: HashMap<char, u8> = SI_TO_CHAR.iter()
+        .enumerate()
+        .map(|(byte, c)| (*c, byte as u8))
+        .collect();
+}
+
+// The characters of Windows-1252 between 128 and 159.
+// Bytes the code page leaves undefined keep their C1 control character.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+fn windows_1252_to_char(byte: u8) -> char {
+    return match byte {
+        128..=159 => WINDOWS_1252_HIGH[(byte - 128) as usize],
+        _ => byte as char,
+    };
+}
+
+// An error from converting a string to SI's code page.
+#[derive(Debug, PartialEq)]
+pub enum EncodeError {
+    // The character has no byte in the code page.
+    Unrepresentable(char),
+    // The string does not fit in the field with its terminating NUL.
+    TooLong { length: usize, max: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            EncodeError::Unrepresentable(c) => write!(f, "'{c}' can not be used in the save"),
+            EncodeError::TooLong { length, max } => write!(f, "{length} characters is over the maximum of {max}"),
+        };
+    }
+}
+
+// Get the character of a byte.
+pub fn si_byte_to_char(byte: u8) -> char {
+    return SI_TO_CHAR[byte as usize];
+}
+
+// Get the byte of a character, if the code page has it.
+pub fn char_to_si_byte(c: char) -> Option<u8> {
+    return CHAR_TO_SI.get(&c).copied();
+}
+
+// Convert a vector of bytes to a string. Every byte has a character, so this can not fail.
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    return bytes.iter()
+        .take_while(|b| **b != 0)
+        .map(|b| si_byte_to_char(*b))
+        .collect();
+}
+
+// Convert a string to a NUL-padded text field of the given length. A field of length 0 has no room for the NUL.
+pub fn string_to_si_bytes(string: &str, len: u8) -> Result<Vec<u8>, EncodeError> {
+    let max = (len as usize).saturating_sub(1);
+    let mut bytes = Vec::with_capacity(len as usize);
+
+    for c in string.chars() {
+        match char_to_si_byte(c) {
+            Some(0) | None => return Err(EncodeError::Unrepresentable(c)),
+            Some(b) => bytes.push(b),
+        }
+    }
+
+    if len == 0 || bytes.len() > max {
+        return Err(EncodeError::TooLong { length: bytes.len(), max });
+    }
+
+    bytes.resize(len as usize, 0);
+    return Ok(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_byte_round_trips() {
+        for byte in 0..=255u8 {
+            assert_eq!(char_to_si_byte(si_byte_to_char(byte)), Some(byte), "byte {byte}");
+        }
+
+        for byte in 1..=255u8 {
+            let string = bytes_to_string(&[byte]);
+            assert_eq!(string_to_si_bytes(&string, 2), Ok(vec![byte, 0]), "byte {byte}");
+        }
+    }
+
+    #[test]
+    fn text_is_nul_padded() {
+        assert_eq!(string_to_si_bytes("Černý", 8), Ok(vec![188, 101, 114, 110, 253, 0, 0, 0]));
+        assert_eq!(bytes_to_string(&[188, 101, 114, 110, 253, 0, 66]), "Černý");
+    }
+
+    #[test]
+    fn invalid_text_is_rejected() {
+        assert_eq!(string_to_si_bytes("Łukasz", 51), Err(EncodeError::Unrepresentable('Ł')));
+        assert_eq!(string_to_si_bytes("a\0", 51), Err(EncodeError::Unrepresentable('\0')));
+        assert_eq!(string_to_si_bytes("abcd", 4), Err(EncodeError::TooLong { length: 4, max: 3 }));
+        assert_eq!(string_to_si_bytes("abc", 4), Ok(vec![97, 98, 99, 0]));
+        assert_eq!(string_to_si_bytes("", 0), Err(EncodeError::TooLong { length: 0, max: 0 }));
+    }
+}
@@ -0,0 +1,71 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+use serde::Serialize;
+
+use crate::{
+    chars::bytes_to_string, data::{Data, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Arena {
+        pub id: i32,
+        pub capacity: i32,
+        pub seating_capacity: i32,
+        pub expansion_capacity: i32,
+        pub city_id: i32,
+        pub nearby_stadium_id: i32,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        _gender_name: i8,
+        rink_size: i8,
+        pub ice_condition: i8,
+    }
+}
+
+impl Arena {
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    pub fn rink_size(&self) -> RinkSize {
+        return RinkSize::from(self.rink_size);
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("City", Table::Cities, self.city_id),
+            ("Nearby Arena", Table::Arenas, self.nearby_stadium_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let arena = Self::read(cursor)?;
+        data.order_arenas.push(arena.id);
+        data.arenas.insert(arena.id, arena);
+
+        return Ok(());
+    }
+}
+
+// The rink sizes of the game's database editor.
+#[derive(Clone, Copy, PartialEq, Serialize)]
+pub enum RinkSize {
+    NorthAmerican,
+    Finnish,
+    Olympic,
+    Unknown(i8),
+}
+
+impl From<i8> for RinkSize {
+    fn from(value: i8) -> Self {
+        return match value {
+            0 => RinkSize::NorthAmerican,
+            1 => RinkSize::Finnish,
+            2 => RinkSize::Olympic,
+            _ => RinkSize::Unknown(value),
+        };
+    }
+}
@@ -0,0 +1,188 @@
+// Checking that the attribute chart can convert every raw attribute value.
+
+use std::{cmp::Ordering, fmt};
+
+use serde::Serialize;
+
+use crate::globals::attr_chart::AttributeChart;
+
+pub const MIN_CA: i16 = 1;
+pub const MAX_CA: i16 = 200;
+pub const MAX_ATTRIBUTE: i8 = 20;
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ChartProblem {
+    // The chart has no ranges for the current ability.
+    MissingCa { ca: i16 },
+    // The current ability has no range for the in-game value.
+    MissingAttribute { ca: i16, attribute: i8 },
+    // A range for an in-game value outside of 1 to 20.
+    UnknownAttribute { ca: i16, attribute: i8 },
+    // A range that contains no raw values.
+    EmptyRange { ca: i16, attribute: i8 },
+    // Raw values that no range of the current ability contains.
+    Gap { ca: i16, start: i8, end: i8 },
+    // A range that starts inside the range of the in-game value below it.
+    Overlap { ca: i16, attribute: i8 },
+    // A range that is higher than the one of the current ability below it. The same in-game value takes a lower raw
+    // value the higher the current ability is.
+    NotMonotonic { ca: i16, attribute: i8 },
+}
+
+impl fmt::Display for ChartProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ChartProblem::MissingCa { ca } => write!(f, "CA {ca} is not in the chart"),
+            ChartProblem::MissingAttribute { ca, attribute } => write!(f, "CA {ca}: {attribute} has no range"),
+            ChartProblem::UnknownAttribute { ca, attribute } => write!(f, "CA {ca}: {attribute} is not an in-game value"),
+            ChartProblem::EmptyRange { ca, attribute } => write!(f, "CA {ca}: the range of {attribute} is empty"),
+            ChartProblem::Gap { ca, start, end } => write!(f, "CA {ca}: {start}..={end} is not in any range"),
+            ChartProblem::Overlap { ca, attribute } => write!(f, "CA {ca}: the range of {attribute} overlaps the one below it"),
+            ChartProblem::NotMonotonic { ca, attribute } => write!(
+                f,
+                "CA {ca}: the range of {attribute} is higher than at CA {}",
+                ca - 1,
+            ),
+        };
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ConversionProblem {
+    // The player's current ability is not in the chart.
+    UnknownCa { player_id: i32, ca: i16 },
+    // No range of the player's current ability contains the raw value.
+    UnconvertibleValue { player_id: i32, ca: i16, attribute: &'static str, raw: i8 },
+}
+
+impl fmt::Display for ConversionProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ConversionProblem::UnknownCa { player_id, ca } => write!(f, "player {player_id}: CA {ca} is not in the chart"),
+            ConversionProblem::UnconvertibleValue { player_id, ca, attribute, raw } => write!(
+                f,
+                "player {player_id}: {attribute} {raw} can not be converted at CA {ca}",
+            ),
+        };
+    }
+}
+
+// Check that every current ability has 20 contiguous ranges that cover every raw value, and that the ranges fall as
+// the current ability rises.
+pub fn validate_chart(chart: &AttributeChart) -> Vec<ChartProblem> {
+    let mut problems = Vec::new();
+
+    for ca in MIN_CA..=MAX_CA {
+        let ranges = match chart.get(&ca) {
+            Some(r) => r,
+            None => {
+                problems.push(ChartProblem::MissingCa { ca });
+                continue;
+            }
+        };
+
+        let mut unknown: Vec<i8> = ranges.keys().filter(|a| !(1..=MAX_ATTRIBUTE).contains(*a)).copied().collect();
+        unknown.sort();
+        problems.extend(unknown.into_iter().map(|attribute| ChartProblem::UnknownAttribute { ca, attribute }));
+
+        // The lowest raw value that is not in a range yet.
+        let mut next_start = i8::MIN as i16;
+        for attribute in 1..=MAX_ATTRIBUTE {
+            let range = match ranges.get(&attribute) {
+                Some(r) => r,
+                None => {
+                    problems.push(ChartProblem::MissingAttribute { ca, attribute });
+                    continue;
+                }
+            };
+
+            if range.is_empty() {
+                problems.push(ChartProblem::EmptyRange { ca, attribute });
+                continue;
+            }
+
+            let start = *range.start() as i16;
+            match start.cmp(&next_start) {
+                Ordering::Greater => problems.push(ChartProblem::Gap { ca, start: next_start as i8, end: (start - 1) as i8 }),
+                Ordering::Less => problems.push(ChartProblem::Overlap { ca, attribute }),
+                Ordering::Equal => (),
+            }
+
+            next_start = next_start.max(*range.end() as i16 + 1);
+        }
+
+        if next_start <= i8::MAX as i16 {
+            problems.push(ChartProblem::Gap { ca, start: next_start as i8, end: i8::MAX });
+        }
+
+        let below = match chart.get(&(ca - 1)) {
+            Some(b) if ca > MIN_CA => b,
+            _ => continue,
+        };
+
+        for attribute in 1..=MAX_ATTRIBUTE {
+            if let (Some(range), Some(range_below)) = (ranges.get(&attribute), below.get(&attribute)) {
+                if range.start() > range_below.start() || range.end() > range_below.end() {
+                    problems.push(ChartProblem::NotMonotonic { ca, attribute });
+                }
+            }
+        }
+    }
+
+    return problems;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::globals::attr_chart::ATTRIBUTE_CHART;
+
+    #[test]
+    fn chart_is_valid() {
+        let problems: Vec<String> = validate_chart(&ATTRIBUTE_CHART).iter().map(|p| p.to_string()).collect();
+        assert!(problems.is_empty(), "{}", problems.join("\n"));
+    }
+
+    #[test]
+    fn broken_ranges_are_found() {
+        let mut chart = ATTRIBUTE_CHART.clone();
+        chart.remove(&7);
+        let ranges = chart.get_mut(&100).unwrap();
+        ranges.remove(&20);
+        ranges.insert(21, 0..=0);
+        let start = *ranges[&5].start();
+        ranges.insert(5, start + 1..=*ranges[&5].end());
+        let end = *ranges[&10].end();
+        ranges.insert(11, end..=*ranges[&11].end());
+
+        // Moving the ranges also moves them against the neighbouring current abilities.
+        let problems: Vec<ChartProblem> = validate_chart(&chart)
+            .into_iter()
+            .filter(|p| !matches!(p, ChartProblem::NotMonotonic { .. }))
+            .collect();
+
+        let start_20 = *ATTRIBUTE_CHART[&100][&20].start();
+        assert_eq!(problems, vec![
+            ChartProblem::MissingCa { ca: 7 },
+            ChartProblem::UnknownAttribute { ca: 100, attribute: 21 },
+            ChartProblem::Gap { ca: 100, start, end: start },
+            ChartProblem::Overlap { ca: 100, attribute: 11 },
+            ChartProblem::MissingAttribute { ca: 100, attribute: 20 },
+            ChartProblem::Gap { ca: 100, start: start_20, end: i8::MAX },
+        ]);
+    }
+
+    #[test]
+    fn rising_ranges_are_found() {
+        let mut chart = ATTRIBUTE_CHART.clone();
+        let below = chart[&49].clone();
+        chart.insert(50, chart[&51].clone());
+        chart.insert(51, below);
+
+        let problems = validate_chart(&chart);
+        assert!(problems.contains(&ChartProblem::NotMonotonic { ca: 51, attribute: 10 }));
+        assert!(problems.iter().all(|p| matches!(p, ChartProblem::NotMonotonic { ca: 51, .. })));
+    }
+}
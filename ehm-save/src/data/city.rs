@@ -0,0 +1,71 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}, nation::Nation, state_province::StateProvince}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct City {
+        pub latitude: f64,
+        pub longitude: f64,
+        pub id: i32,
+        state_id: i32,
+        nation_id: i32,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        pub _b_name: Vec<u8>,
+        _gender_name: i8,
+        _attraction: i8,
+    }
+}
+
+impl City {
+    pub fn _name(&self) -> String {
+        return bytes_to_string(&self._b_name);
+    }
+
+    // Cities without a known location are at 0, 0.
+    pub fn has_coordinates(&self) -> bool {
+        return self.latitude != 0.0 || self.longitude != 0.0;
+    }
+
+    pub fn nation<'a>(&self, data: &'a Data) -> Option<&'a Nation> {
+        return data.nations.get(&self.nation_id);
+    }
+
+    pub fn nation_three_letter_name(&self, data: &Data) -> Option<String> {
+        match data.nations.get(&self.nation_id) {
+            Some(n) => Some(n._three_letter_name()),
+            None => None,
+        }
+    }
+
+    pub fn state<'a>(&self, data: &'a Data) -> Option<&'a StateProvince> {
+        return data.states_provinces.get(&self.state_id);
+    }
+
+    pub fn state_abbreviation(&self, data: &Data) -> Option<String> {
+        match data.states_provinces.get(&self.state_id) {
+            Some(s) => Some(s._abbreviation()),
+            None => None,
+        }
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+            ("State/Province", Table::StatesProvinces, self.state_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let city = Self::read(cursor)?;
+        data.order_cities.push(city.id);
+        data.cities.insert(city.id, city);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,234 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, REAL_SHORT_TEXT_LENGTH, SHORT_TEXT_LENGTH, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}, set_ids}, record::record
+};
+
+// The most players a club can have in its squad.
+pub const SQUAD_SIZE: usize = 75;
+
+record! {
+    #[derive(Clone)]
+    pub struct Club {
+        id: i32,
+        pub cash: i32,
+        _attendance: i32,
+        _min_attendance: i32,
+        _max_attendance: i32,
+        _euro_flag: i32,
+        _tactic_training: [i32; 4],
+        _tactic_selected: i32,
+        _fan_support: i32,
+        pub player_budget: i32,
+        pub nation_id: i32,
+        pub division_id: i32,
+        last_division_id: i32,
+        pub reserve_division_id: i32,
+        pub stadium_id: i32,
+        practice_facilities_id: i32,
+        foreground_colour1_id: i32,
+        background_colour1_id: i32,
+        trim_colour1_id: i32,
+        foreground_colour2_id: i32,
+        background_colour2_id: i32,
+        trim_colour2_id: i32,
+        foreground_colour3_id: i32,
+        background_colour3_id: i32,
+        trim_colour3_id: i32,
+        favourite_staff1_id: i32,
+        favourite_staff2_id: i32,
+        favourite_staff3_id: i32,
+        disliked_staff1_id: i32,
+        disliked_staff2_id: i32,
+        disliked_staff3_id: i32,
+        rival1_id: i32,
+        rival2_id: i32,
+        rival3_id: i32,
+        chairman_id: i32,
+        director_ids: [i32; 3],
+        manager_id: i32,
+        assistant_manager_id: i32,
+        head_coach_id: i32,
+        head_scout_id: i32,
+        #[br(count = SQUAD_SIZE)]
+        squad_ids: Vec<i32>,
+        coach_ids: [i32; 5],
+        scout_ids: [i32; 15],
+        physio_ids: [i32; 3],
+        captain_id: i32,
+        assistant_captain1_id: i32,
+        assistant_captain2_id: i32,
+        _reputation: i16,
+        _year_founded: i16,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        pub b_name: Vec<u8>,
+        b_short_name: [u8; SHORT_TEXT_LENGTH as usize],
+        b_abbreviation: [u8; REAL_SHORT_TEXT_LENGTH as usize],
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_nickname: Vec<u8>,
+        _gender_name_short: i8,
+        _last_position: i8,
+        _professional_status: i8,
+        _own_stadium: i8,
+        _home_match_day: i8,
+        _training: i8,
+        _plc: i8,
+        _gender_name: i8,
+        _euro_seeding: i8,
+        _has_linked_club: i8,
+        _market_size: i8,
+    }
+}
+
+impl Club {
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    pub fn _short_name(&self) -> String {
+        return bytes_to_string(&self.b_short_name);
+    }
+
+    fn _abbreviation(&self) -> String {
+        return bytes_to_string(&self.b_abbreviation);
+    }
+
+    fn _nickname(&self) -> String {
+        return bytes_to_string(&self.b_nickname);
+    }
+
+    // Get the text fields that can be renamed.
+    pub fn text_fields(&self) -> Vec<(&'static str, &[u8])> {
+        return Vec::from([
+            ("Name", self.b_name.as_slice()),
+            ("Short Name", self.b_short_name.as_slice()),
+            ("Abbreviation", self.b_abbreviation.as_slice()),
+            ("Nickname", self.b_nickname.as_slice()),
+        ]);
+    }
+
+    pub fn text_field_mut(&mut self, field: &str) -> Option<&mut [u8]> {
+        return match field {
+            "Name" => Some(&mut self.b_name),
+            "Short Name" => Some(&mut self.b_short_name),
+            "Abbreviation" => Some(&mut self.b_abbreviation),
+            "Nickname" => Some(&mut self.b_nickname),
+            _ => None,
+        };
+    }
+
+    // Get the IDs of the club's favourite staff that are set.
+    pub fn favourite_staff_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.favourite_staff1_id, self.favourite_staff2_id, self.favourite_staff3_id]);
+    }
+
+    // Get the IDs of the club's disliked staff that are set.
+    pub fn disliked_staff_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.disliked_staff1_id, self.disliked_staff2_id, self.disliked_staff3_id]);
+    }
+
+    // Get the IDs of the club's rivals that are set.
+    pub fn rival_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.rival1_id, self.rival2_id, self.rival3_id]);
+    }
+
+    // Get the IDs of the staff in the squad.
+    pub fn squad_ids(&self) -> Vec<i32> {
+        return set_ids(&self.squad_ids);
+    }
+
+    pub fn has_free_squad_slot(&self) -> bool {
+        return self.squad_ids.contains(&-1);
+    }
+
+    // Add the person to the first free slot of the squad. Return false if the squad is full.
+    pub fn add_to_squad(&mut self, staff_id: i32) -> bool {
+        if self.squad_ids.contains(&staff_id) {
+            return true;
+        }
+
+        return match self.squad_ids.iter_mut().find(|id| **id == -1) {
+            Some(slot) => {
+                *slot = staff_id;
+                true
+            }
+            None => false,
+        };
+    }
+
+    // Remove the person from the squad, freeing their slot.
+    pub fn remove_from_squad(&mut self, staff_id: i32) {
+        self.squad_ids.iter_mut()
+            .filter(|id| **id == staff_id)
+            .for_each(|id| *id = -1);
+    }
+
+    // Get the IDs this refers to in other tables.
+    // NOTE: National teams are checked against the national clubs and competitions.
+    pub fn references(&self) -> Vec<Reference> {
+        let mut references = Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+            ("Division", Table::Competitions, self.division_id),
+            ("Last Division", Table::Competitions, self.last_division_id),
+            ("Reserve Division", Table::Competitions, self.reserve_division_id),
+            ("Arena", Table::Arenas, self.stadium_id),
+            ("Practice Facilities", Table::Arenas, self.practice_facilities_id),
+            ("Foreground Colour 1", Table::Colours, self.foreground_colour1_id),
+            ("Background Colour 1", Table::Colours, self.background_colour1_id),
+            ("Trim Colour 1", Table::Colours, self.trim_colour1_id),
+            ("Foreground Colour 2", Table::Colours, self.foreground_colour2_id),
+            ("Background Colour 2", Table::Colours, self.background_colour2_id),
+            ("Trim Colour 2", Table::Colours, self.trim_colour2_id),
+            ("Foreground Colour 3", Table::Colours, self.foreground_colour3_id),
+            ("Background Colour 3", Table::Colours, self.background_colour3_id),
+            ("Trim Colour 3", Table::Colours, self.trim_colour3_id),
+            ("Favourite Staff 1", Table::Staff, self.favourite_staff1_id),
+            ("Favourite Staff 2", Table::Staff, self.favourite_staff2_id),
+            ("Favourite Staff 3", Table::Staff, self.favourite_staff3_id),
+            ("Disliked Staff 1", Table::Staff, self.disliked_staff1_id),
+            ("Disliked Staff 2", Table::Staff, self.disliked_staff2_id),
+            ("Disliked Staff 3", Table::Staff, self.disliked_staff3_id),
+            ("Rival 1", Table::Clubs, self.rival1_id),
+            ("Rival 2", Table::Clubs, self.rival2_id),
+            ("Rival 3", Table::Clubs, self.rival3_id),
+            ("Chairman", Table::Staff, self.chairman_id),
+            ("Manager", Table::Staff, self.manager_id),
+            ("Assistant Manager", Table::Staff, self.assistant_manager_id),
+            ("Head Coach", Table::Staff, self.head_coach_id),
+            ("Head Scout", Table::Staff, self.head_scout_id),
+            ("Captain", Table::Staff, self.captain_id),
+            ("Assistant Captain 1", Table::Staff, self.assistant_captain1_id),
+            ("Assistant Captain 2", Table::Staff, self.assistant_captain2_id),
+        ]);
+
+        for (field, ids) in [
+            ("Director", self.director_ids.as_slice()),
+            ("Squad", self.squad_ids.as_slice()),
+            ("Coach", self.coach_ids.as_slice()),
+            ("Scout", self.scout_ids.as_slice()),
+            ("Physio", self.physio_ids.as_slice()),
+        ] {
+            references.extend(ids.iter().map(|id| (field, Table::Staff, *id)));
+        }
+
+        return references;
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let club = Self::read(cursor)?;
+        data.order_clubs.push(club.id);
+        data.clubs.insert(club.id, club);
+
+        return Ok(());
+    }
+
+    pub fn parse_nat(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let club = Self::read(cursor)?;
+        data.order_nat_clubs.push(club.id);
+        data.nat_clubs.insert(club.id, club);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,33 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, STANDARD_TEXT_LENGTH}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Colour {
+        id: i32,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        _b_name: Vec<u8>,
+        _red: u8,
+        _green: u8,
+        _blue: u8,
+    }
+}
+
+impl Colour {
+    fn _name(&self) -> String {
+        return bytes_to_string(&self._b_name);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let colour = Self::read(cursor)?;
+        data.order_colours.push(colour.id);
+        data.colours.insert(colour.id, colour);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,106 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, SHORT_TEXT_LENGTH, SIX_LETTER_TEXT_LENGTH, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Competition {
+        pub id: i32,
+        continent_id: i32,
+        nation_id: i32,
+        foreground_colour_id: i32,
+        background_colour_id: i32,
+        trim_colour_id: i32,
+        b_six_letter_name: [u8; SIX_LETTER_TEXT_LENGTH as usize],
+        _gender_name_short: i8,
+        _scope: i8,
+        _reputation: i16,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        b_short_name: [u8; SHORT_TEXT_LENGTH as usize],
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_playoff_trophy_name: Vec<u8>,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_regular_season_trophy_name: Vec<u8>,
+        _playoff_trophy_gender: i8,
+        _regular_season_trophy_gender: i8,
+        _selected: i8,
+        _gender_name: i8,
+        _upper_age_limit: i8,
+    }
+}
+
+impl Competition {
+    fn _six_letter_name(&self) -> String {
+        return bytes_to_string(&self.b_six_letter_name);
+    }
+
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    fn _short_name(&self) -> String {
+        return bytes_to_string(&self.b_short_name);
+    }
+
+    fn _playoff_trophy_name(&self) -> String {
+        return bytes_to_string(&self.b_playoff_trophy_name);
+    }
+
+    fn _regular_season_trophy_name(&self) -> String {
+        return bytes_to_string(&self.b_regular_season_trophy_name);
+    }
+
+    // Get the text fields that can be renamed.
+    pub fn text_fields(&self) -> Vec<(&'static str, &[u8])> {
+        return Vec::from([
+            ("Name", self.b_name.as_slice()),
+            ("Short Name", self.b_short_name.as_slice()),
+            ("Six Letter Name", self.b_six_letter_name.as_slice()),
+            ("Playoff Trophy Name", self.b_playoff_trophy_name.as_slice()),
+            ("Regular Season Trophy Name", self.b_regular_season_trophy_name.as_slice()),
+        ]);
+    }
+
+    pub fn text_field_mut(&mut self, field: &str) -> Option<&mut [u8]> {
+        return match field {
+            "Name" => Some(&mut self.b_name),
+            "Short Name" => Some(&mut self.b_short_name),
+            "Six Letter Name" => Some(&mut self.b_six_letter_name),
+            "Playoff Trophy Name" => Some(&mut self.b_playoff_trophy_name),
+            "Regular Season Trophy Name" => Some(&mut self.b_regular_season_trophy_name),
+            _ => None,
+        };
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Continent", Table::Continents, self.continent_id),
+            ("Nation", Table::Nations, self.nation_id),
+            ("Foreground Colour", Table::Colours, self.foreground_colour_id),
+            ("Background Colour", Table::Colours, self.background_colour_id),
+            ("Trim Colour", Table::Colours, self.trim_colour_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let comp = Self::read(cursor)?;
+        data.order_competitions.push(comp.id);
+        data.competitions.insert(comp.id, comp);
+
+        return Ok(());
+    }
+
+    pub fn parse_nat(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let comp = Self::read(cursor)?;
+        data.order_nat_competitions.push(comp.id);
+        data.nat_competitions.insert(comp.id, comp);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,48 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{data::{Data, integrity::{Reference, Table}}, record::record};
+
+record! {
+    #[derive(Clone)]
+    pub struct CompetitionHistory {
+        id: i32,
+        comp_id: i32,
+        winner_id: i32,
+        runner_up_id: i32,
+        third_placed_id: i32,
+        _host_id: i32,
+        regular_season_winner_id: i32,
+        _year: i16,
+    }
+}
+
+impl CompetitionHistory {
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Competition", Table::Competitions, self.comp_id),
+            ("Winner", Table::Clubs, self.winner_id),
+            ("Runner Up", Table::Clubs, self.runner_up_id),
+            ("Third Placed", Table::Clubs, self.third_placed_id),
+            ("Regular Season Winner", Table::Clubs, self.regular_season_winner_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let history = Self::read(cursor)?;
+        data.order_comp_history.push(history.id);
+        data.comp_history.insert(history.id, history);
+
+        return Ok(());
+    }
+
+    pub fn parse_nat(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let history = Self::read(cursor)?;
+        data.order_nat_comp_history.push(history.id);
+        data.nat_comp_history.insert(history.id, history);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, SHORT_TEXT_LENGTH, THREE_LETTER_TEXT_LENGTH}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Continent {
+        _regional_strength: f64,
+        id: i32,
+        _b_three_letter_name: [u8; THREE_LETTER_TEXT_LENGTH as usize],
+        _b_name: [u8; SHORT_TEXT_LENGTH as usize],
+        _b_continentality_name: [u8; SHORT_TEXT_LENGTH as usize],
+        _gender_name: i8,
+    }
+}
+
+impl Continent {
+    fn _three_letter_name(&self) -> String {
+        return bytes_to_string(&self._b_three_letter_name);
+    }
+
+    fn _name(&self) -> String {
+        return bytes_to_string(&self._b_name);
+    }
+
+    fn _continentality_name(&self) -> String {
+        return bytes_to_string(&self._b_continentality_name);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let continent = Self::read(cursor)?;
+        data.order_continents.push(continent.id);
+        data.continents.insert(continent.id, continent);
+
+        return Ok(());
+    }
+}
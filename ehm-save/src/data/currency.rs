@@ -0,0 +1,63 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, SHORT_TEXT_LENGTH, STANDARD_TEXT_LENGTH, THREE_LETTER_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Currency {
+        pub id: i32,
+        pub nation_id: i32,
+        exchange_rate: f64,
+        _approx_exchange_rate: f64,
+        _very_approx_exchange_rate: f64,
+        _name_gender: i8,
+        _short_name_gender: i8,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        _b_short_name: [u8; SHORT_TEXT_LENGTH as usize],
+        b_three_letter_code: [u8; THREE_LETTER_TEXT_LENGTH as usize],
+        b_symbol: [u8; THREE_LETTER_TEXT_LENGTH as usize],
+    }
+}
+
+impl Currency {
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    fn _short_name(&self) -> String {
+        return bytes_to_string(&self._b_short_name);
+    }
+
+    pub fn three_letter_code(&self) -> String {
+        return bytes_to_string(&self.b_three_letter_code);
+    }
+
+    pub fn symbol(&self) -> String {
+        return bytes_to_string(&self.b_symbol);
+    }
+
+    // Convert an amount from the game's base currency to this currency.
+    pub fn convert(&self, amount: i32) -> f64 {
+        return amount as f64 * self.exchange_rate;
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let currency = Self::read(cursor)?;
+        data.order_currencies.push(currency.id);
+        data.currencies.insert(currency.id, currency);
+
+        return Ok(());
+    }
+}
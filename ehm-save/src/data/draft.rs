@@ -0,0 +1,44 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Draft {
+        pub id: i32,
+        pub comp_id: i32,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        _gender_name: i8,
+        b_is_extinct: u8,
+    }
+}
+
+impl Draft {
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    pub fn is_extinct(&self) -> bool {
+        return self.b_is_extinct != 0;
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Competition", Table::Competitions, self.comp_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let draft = Self::read(cursor)?;
+        data.order_drafts.push(draft.id);
+        data.drafts.insert(draft.id, draft);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, STANDARD_TEXT_LENGTH}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Injury {
+        pub minimum_days_out: i16,
+        pub extra_days_out: i16,
+        pub id: i16,
+        pub category: i8,
+        pub chance: i8,
+        pub inactive_ratio: i8,
+        b_is_recurring: u8,
+        pub severity: i8,
+        pub cause: i8,
+        _gender: i8,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name_dative: Vec<u8>,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name_genetive: Vec<u8>,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name_special_nominative: Vec<u8>,
+    }
+}
+
+impl Injury {
+    pub fn is_recurring(&self) -> bool {
+        return self.b_is_recurring != 0;
+    }
+
+    // Get the shortest and longest time the injury keeps a person out, in days.
+    pub fn days_out_range(&self) -> (i32, i32) {
+        let minimum = self.minimum_days_out as i32;
+        return (minimum, minimum + self.extra_days_out as i32);
+    }
+
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    pub fn name_dative(&self) -> String {
+        return bytes_to_string(&self.b_name_dative);
+    }
+
+    pub fn name_genetive(&self) -> String {
+        return bytes_to_string(&self.b_name_genetive);
+    }
+
+    pub fn name_special_nominative(&self) -> String {
+        return bytes_to_string(&self.b_name_special_nominative);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let injury = Self::read(cursor)?;
+        data.order_injuries.push(injury.id);
+        data.injuries.insert(injury.id, injury);
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_injuries_do_not_overflow() {
+        let injury = Injury {
+            minimum_days_out: i16::MAX,
+            extra_days_out: i16::MAX,
+            id: 0,
+            category: 0,
+            chance: 0,
+            inactive_ratio: 0,
+            b_is_recurring: 0,
+            severity: 0,
+            cause: 0,
+            _gender: 0,
+            b_name: Vec::new(),
+            b_name_dative: Vec::new(),
+            b_name_genetive: Vec::new(),
+            b_name_special_nominative: Vec::new(),
+        };
+
+        assert_eq!(injury.days_out_range(), (32767, 65534));
+    }
+}
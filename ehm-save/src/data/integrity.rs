@@ -0,0 +1,396 @@
+// Checking that the IDs in the data refer to something.
+
+use std::{collections::{HashMap, HashSet}, fmt};
+
+use serde::Serialize;
+
+use crate::data::Data;
+
+// A field, the table it refers to and the ID in it.
+pub type Reference = (&'static str, Table, i32);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Table {
+    Continents,
+    Officials,
+    Forenames,
+    Surnames,
+    Cities,
+    Clubs,
+    NatClubs,
+    StaffAwards,
+    Competitions,
+    NatCompetitions,
+    CompHistory,
+    NatCompHistory,
+    Colours,
+    Nations,
+    Arenas,
+    Staff,
+    NonPlayers,
+    Players,
+    StaffPreferences,
+    RetiredNumbers,
+    StatesProvinces,
+    Injuries,
+    Currencies,
+    Drafts,
+    StageNames,
+}
+
+impl Table {
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Table::Continents => "continent",
+            Table::Officials => "official",
+            Table::Forenames => "forename",
+            Table::Surnames => "surname",
+            Table::Cities => "city",
+            Table::Clubs => "club",
+            Table::NatClubs => "national club",
+            Table::StaffAwards => "staff award",
+            Table::Competitions => "competition",
+            Table::NatCompetitions => "national competition",
+            Table::CompHistory => "competition history",
+            Table::NatCompHistory => "national competition history",
+            Table::Colours => "colour",
+            Table::Nations => "nation",
+            Table::Arenas => "arena",
+            Table::Staff => "staff",
+            Table::NonPlayers => "non-player",
+            Table::Players => "player",
+            Table::StaffPreferences => "staff preferences",
+            Table::RetiredNumbers => "retired number",
+            Table::StatesProvinces => "state/province",
+            Table::Injuries => "injury",
+            Table::Currencies => "currency",
+            Table::Drafts => "draft",
+            Table::StageNames => "stage name",
+        };
+    }
+
+    // Get the table of the national version of the records, if it has one.
+    fn national(self) -> Self {
+        return match self {
+            Table::Clubs => Table::NatClubs,
+            Table::Competitions => Table::NatCompetitions,
+            _ => self,
+        };
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SquadIssue {
+    // The person is in the squad more than once.
+    Duplicate,
+    // The person is neither contracted to nor playing for the club.
+    NotAtClub,
+    // The player is contracted to the club but is not in its squad.
+    MissingFromSquad,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum Problem {
+    // A reference to a record that does not exist.
+    DanglingId { table: Table, id: i32, field: &'static str, target: Table, target_id: i32 },
+    // A record that nothing refers to.
+    Orphan { table: Table, id: i32 },
+    // A record whose ID is used by another record in the same table.
+    DuplicateId { table: Table, id: i32, count: usize },
+    Squad { club_id: i32, staff_id: i32, issue: SquadIssue },
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Problem::DanglingId { table, id, field, target, target_id } => write!(
+                f,
+                "{} {id}: {field} refers to {} {target_id}, which does not exist",
+                table.name(),
+                target.name(),
+            ),
+            Problem::Orphan { table, id } => write!(f, "{} {id} is not used by anything", table.name()),
+            Problem::DuplicateId { table, id, count } => write!(f, "{} {id} exists {count} times", table.name()),
+            Problem::Squad { club_id, staff_id, issue } => match issue {
+                SquadIssue::Duplicate => write!(f, "club {club_id}: staff {staff_id} is in the squad more than once"),
+                SquadIssue::NotAtClub => write!(f, "club {club_id}: staff {staff_id} is in the squad but not at the club"),
+                SquadIssue::MissingFromSquad => write!(f, "club {club_id}: player {staff_id} is contracted but not in the squad"),
+            },
+        };
+    }
+}
+
+impl Data {
+    // Find dangling IDs, orphaned and duplicate records and inconsistent squads.
+    pub fn check_integrity(&self) -> Vec<Problem> {
+        let mut problems = self.duplicate_ids();
+
+        // Every record with the table it is in, and the tables its references would be in.
+        let mut records: Vec<(Table, i32, Vec<Reference>, bool)> = Vec::new();
+        let mut add = |table: Table, id: i32, references: Vec<Reference>, national: bool| {
+            records.push((table, id, references, national));
+        };
+
+        self.order_forenames.iter().for_each(|id| add(Table::Forenames, *id, self.forenames[id].references(), false));
+        self.order_surnames.iter().for_each(|id| add(Table::Surnames, *id, self.surnames[id].references(), false));
+        self.order_officials.iter().for_each(|id| add(Table::Officials, *id, self.officials[id].references(), false));
+        self.order_cities.iter().for_each(|id| add(Table::Cities, *id, self.cities[id].references(), false));
+        self.order_clubs.iter().for_each(|id| add(Table::Clubs, *id, self.clubs[id].references(), false));
+        self.order_nat_clubs.iter().for_each(|id| add(Table::NatClubs, *id, self.nat_clubs[id].references(), true));
+        self.order_staff_awards.iter().for_each(|id| add(Table::StaffAwards, *id, self.staff_awards[id].references(), false));
+        self.order_competitions.iter().for_each(|id| add(Table::Competitions, *id, self.competitions[id].references(), false));
+        self.order_nat_competitions.iter().for_each(|id| add(Table::NatCompetitions, *id, self.nat_competitions[id].references(), true));
+        self.order_comp_history.iter().for_each(|id| add(Table::CompHistory, *id, self.comp_history[id].references(), false));
+        self.order_nat_comp_history.iter().for_each(|id| add(Table::NatCompHistory, *id, self.nat_comp_history[id].references(), true));
+        self.order_nations.iter().for_each(|id| add(Table::Nations, *id, self.nations[id].references(), false));
+        self.order_arenas.iter().for_each(|id| add(Table::Arenas, *id, self.arenas[id].references(), false));
+        self.order_staff.iter().for_each(|id| add(Table::Staff, *id, self.staff[id].references(), false));
+        self.order_staff_preferences.iter().for_each(|id| add(Table::StaffPreferences, *id, self.staff_preferences[id].references(), false));
+        self.order_retired_numbers.iter().for_each(|id| add(Table::RetiredNumbers, *id, self.retired_numbers[id].references(), false));
+        self.order_states_provinces.iter().for_each(|id| add(Table::StatesProvinces, *id, self.states_provinces[id].references(), false));
+        self.order_currencies.iter().for_each(|id| add(Table::Currencies, *id, self.currencies[id].references(), false));
+        self.order_drafts.iter().for_each(|id| add(Table::Drafts, *id, self.drafts[id].references(), false));
+
+        let nonplayer_ids: HashSet<i32> = self.nonplayers.iter().map(|(id, _)| *id).collect();
+        let mut referenced = HashSet::new();
+        for (table, id, references, national) in records {
+            for (field, target, target_id) in references {
+                if target_id == -1 {
+                    continue;
+                }
+
+                let target = match national {
+                    true => target.national(),
+                    false => target,
+                };
+
+                referenced.insert((target, target_id));
+                let exists = match target {
+                    Table::NonPlayers => nonplayer_ids.contains(&target_id),
+                    _ => self.contains(target, target_id),
+                };
+
+                if !exists {
+                    problems.push(Problem::DanglingId { table, id, field, target, target_id });
+                }
+            }
+        }
+
+        // Only these records exist solely to be used by other records.
+        let mut orphans: Vec<(Table, i32)> = Vec::new();
+        orphans.extend(self.order_players.iter().map(|id| (Table::Players, *id)));
+        orphans.extend(self.order_staff_preferences.iter().map(|id| (Table::StaffPreferences, *id)));
+        orphans.extend(self.nonplayers.iter().map(|(id, _)| (Table::NonPlayers, *id)));
+        problems.extend(orphans.into_iter()
+            .filter(|record| !referenced.contains(record))
+            .map(|(table, id)| Problem::Orphan { table, id }));
+
+        problems.append(&mut self.squad_problems());
+        return problems;
+    }
+
+    // Find the IDs that more than one record in the same table has.
+    fn duplicate_ids(&self) -> Vec<Problem> {
+        let injuries: Vec<i32> = self.order_injuries.iter().map(|id| *id as i32).collect();
+        let nonplayers: Vec<i32> = self.nonplayers.iter().map(|(id, _)| *id).collect();
+
+        let tables = [
+            (Table::Continents, &self.order_continents),
+            (Table::Officials, &self.order_officials),
+            (Table::Forenames, &self.order_forenames),
+            (Table::Surnames, &self.order_surnames),
+            (Table::Cities, &self.order_cities),
+            (Table::Clubs, &self.order_clubs),
+            (Table::NatClubs, &self.order_nat_clubs),
+            (Table::StaffAwards, &self.order_staff_awards),
+            (Table::Competitions, &self.order_competitions),
+            (Table::NatCompetitions, &self.order_nat_competitions),
+            (Table::CompHistory, &self.order_comp_history),
+            (Table::NatCompHistory, &self.order_nat_comp_history),
+            (Table::Colours, &self.order_colours),
+            (Table::Nations, &self.order_nations),
+            (Table::Arenas, &self.order_arenas),
+            (Table::Staff, &self.order_staff),
+            (Table::NonPlayers, &nonplayers),
+            (Table::Players, &self.order_players),
+            (Table::StaffPreferences, &self.order_staff_preferences),
+            (Table::RetiredNumbers, &self.order_retired_numbers),
+            (Table::StatesProvinces, &self.order_states_provinces),
+            (Table::Injuries, &injuries),
+            (Table::Currencies, &self.order_currencies),
+            (Table::Drafts, &self.order_drafts),
+            (Table::StageNames, &self.order_stage_names),
+        ];
+
+        let mut problems = Vec::new();
+        for (table, ids) in tables {
+            let mut counts: HashMap<i32, usize> = HashMap::new();
+            for id in ids {
+                *counts.entry(*id).or_default() += 1;
+            }
+
+            // Keep the order of the first appearance.
+            let mut reported = HashSet::new();
+            for id in ids {
+                let count = counts[id];
+                if count > 1 && reported.insert(*id) {
+                    problems.push(Problem::DuplicateId { table, id: *id, count });
+                }
+            }
+        }
+
+        return problems;
+    }
+
+    // Find squads that do not match the clubs of the people in them.
+    fn squad_problems(&self) -> Vec<Problem> {
+        let mut problems = Vec::new();
+
+        for club_id in self.order_clubs.iter() {
+            let mut seen = HashSet::new();
+            for staff_id in self.clubs[club_id].squad_ids() {
+                if !seen.insert(staff_id) {
+                    problems.push(Problem::Squad { club_id: *club_id, staff_id, issue: SquadIssue::Duplicate });
+                    continue;
+                }
+
+                // Missing people are reported as dangling IDs.
+                let person = match self.staff.get(&staff_id) {
+                    Some(p) => p,
+                    None => continue,
+                };
+
+                if person.club_contracted_id != *club_id && person.club_playing_id != *club_id {
+                    problems.push(Problem::Squad { club_id: *club_id, staff_id, issue: SquadIssue::NotAtClub });
+                }
+            }
+        }
+
+        for staff_id in self.order_staff.iter() {
+            let person = &self.staff[staff_id];
+            let club = match self.clubs.get(&person.club_contracted_id) {
+                Some(c) => c,
+                None => continue,
+            };
+
+            if person.player_data(self).is_some() && !club.squad_ids().contains(staff_id) {
+                problems.push(Problem::Squad {
+                    club_id: person.club_contracted_id,
+                    staff_id: *staff_id,
+                    issue: SquadIssue::MissingFromSquad,
+                });
+            }
+        }
+
+        return problems;
+    }
+
+    // Check if the table has a record with the ID.
+    fn contains(&self, table: Table, id: i32) -> bool {
+        return match table {
+            Table::Continents => self.continents.contains_key(&id),
+            Table::Officials => self.officials.contains_key(&id),
+            Table::Forenames => self.forenames.contains_key(&id),
+            Table::Surnames => self.surnames.contains_key(&id),
+            Table::Cities => self.cities.contains_key(&id),
+            Table::Clubs => self.clubs.contains_key(&id),
+            Table::NatClubs => self.nat_clubs.contains_key(&id),
+            Table::StaffAwards => self.staff_awards.contains_key(&id),
+            Table::Competitions => self.competitions.contains_key(&id),
+            Table::NatCompetitions => self.nat_competitions.contains_key(&id),
+            Table::CompHistory => self.comp_history.contains_key(&id),
+            Table::NatCompHistory => self.nat_comp_history.contains_key(&id),
+            Table::Colours => self.colours.contains_key(&id),
+            Table::Nations => self.nations.contains_key(&id),
+            Table::Arenas => self.arenas.contains_key(&id),
+            Table::Staff => self.staff.contains_key(&id),
+            Table::NonPlayers => self.nonplayers.iter().any(|(i, _)| *i == id),
+            Table::Players => self.players.contains_key(&id),
+            Table::StaffPreferences => self.staff_preferences.contains_key(&id),
+            Table::RetiredNumbers => self.retired_numbers.contains_key(&id),
+            Table::StatesProvinces => self.states_provinces.contains_key(&id),
+            Table::Injuries => self.injuries.contains_key(&(id as i16)),
+            Table::Currencies => self.currencies.contains_key(&id),
+            Table::Drafts => self.drafts.contains_key(&id),
+            Table::StageNames => self.stage_names.contains_key(&id),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data::{club::Club, non_player::NonPlayer, player::Player, staff::Staff}, record::test_record};
+
+    use super::*;
+
+    fn person(id: i32, club_contracted_id: i32, club_playing_id: i32) -> Staff {
+        let mut person = Staff::default();
+        person.id = id;
+        person.club_contracted_id = club_contracted_id;
+        person.club_playing_id = club_playing_id;
+        return person;
+    }
+
+    // A club with two people in its squad: a player contracted to a club that does not exist, and a person at
+    // another club. There are two non-players with the same ID.
+    fn data() -> Data {
+        let mut data = Data::new();
+
+        let mut club: Club = test_record(&[("id", &1i32.to_le_bytes())]);
+        club.remove_from_squad(0);
+        club.add_to_squad(1);
+        club.add_to_squad(2);
+        data.clubs.insert(1, club);
+        data.order_clubs.push(1);
+
+        for person in [person(1, 99, 1), person(2, 3, 3)] {
+            data.order_staff.push(person.id);
+            data.staff.insert(person.id, person);
+        }
+
+        data.players.insert(0, Player::default());
+        data.order_players.push(0);
+        for _ in 0..2 {
+            data.nonplayers.push((7, test_record::<NonPlayer>(&[("id", &7i32.to_le_bytes())])));
+        }
+
+        return data;
+    }
+
+    fn count(problems: &[Problem], problem: &Problem) -> usize {
+        return problems.iter().filter(|p| *p == problem).count();
+    }
+
+    #[test]
+    fn problems_are_reported_once() {
+        let problems = data().check_integrity();
+
+        let dangling = Problem::DanglingId {
+            table: Table::Staff,
+            id: 1,
+            field: "Club Contracted",
+            target: Table::Clubs,
+            target_id: 99,
+        };
+        assert_eq!(count(&problems, &dangling), 1);
+
+        let duplicate = Problem::DuplicateId { table: Table::NonPlayers, id: 7, count: 2 };
+        assert_eq!(count(&problems, &duplicate), 1);
+
+        let not_at_club = Problem::Squad { club_id: 1, staff_id: 2, issue: SquadIssue::NotAtClub };
+        assert_eq!(count(&problems, &not_at_club), 1);
+
+        // The player plays for the club, so being in its squad is fine.
+        assert!(!problems.iter().any(|p| matches!(p, Problem::Squad { staff_id: 1, .. })));
+    }
+
+    #[test]
+    fn duplicates_and_squads_are_found() {
+        let data = data();
+        assert_eq!(data.duplicate_ids(), [Problem::DuplicateId { table: Table::NonPlayers, id: 7, count: 2 }]);
+        assert_eq!(data.squad_problems(), [Problem::Squad { club_id: 1, staff_id: 2, issue: SquadIssue::NotAtClub }]);
+    }
+}
@@ -1,5 +1,6 @@
 // Data types for storing human-readable values.
 pub mod arena;
+pub mod chart_check;
 pub mod city;
 pub mod club;
 pub mod colour;
@@ -9,6 +10,7 @@ pub mod continent;
 pub mod currency;
 pub mod draft;
 pub mod injury;
+pub mod integrity;
 pub mod name;
 pub mod nation;
 pub mod non_player;
@@ -21,20 +23,19 @@ pub mod staff_preferences;
 pub mod stage_name;
 pub mod state_province;
 
-use std::{cmp::Ordering, collections::HashMap, i16, io::Cursor, mem};
+use std::{cmp::Ordering, collections::HashMap, fmt, fs, hash::Hash, i16, io::{self, Cursor}, mem, path::Path};
 
-use binread::BinRead;
-use tauri::webview::cookie::time::util::is_leap_year;
+use binread::{BinRead, BinResult};
 
 use crate::{
     data::{
-        arena::Arena, city::City, club::Club, colour::Colour, competition::Competition,
-        competition_history::CompetitionHistory, continent::Continent, currency::Currency,
+        arena::Arena, chart_check::ConversionProblem, city::City, club::Club, colour::Colour,
+        competition::Competition, competition_history::CompetitionHistory, continent::Continent, currency::Currency,
         draft::Draft, injury::Injury, name::Name, nation::Nation, non_player::NonPlayer,
         official::Official, player::Player, retired_number::RetiredNumber, staff::Staff,
         staff_award::StaffAward, staff_preferences::StaffPreferences, stage_name::StageName,
         state_province::StateProvince,
-    }, globals::attr_chart::ATTRIBUTE_CHART, init::{FileIndex, Header, read_file_indexes}
+    }, filters::PlayerFilters, globals::attr_chart::ATTRIBUTE_CHART, init::{Binary, FileIndex, Header, read_file_indexes}, record::{Record, record}
 };
 
 static LONG_TEXT_LENGTH: u8 = 101;
@@ -44,6 +45,23 @@ static REAL_SHORT_TEXT_LENGTH: u8 = 6;
 static THREE_LETTER_TEXT_LENGTH: u8 = 4;
 static SIX_LETTER_TEXT_LENGTH: u8 = 7;
 
+#[derive(Debug)]
+pub enum SaveError {
+    // The data was loaded from a database folder, so it has no save file header or parts.
+    NotASave,
+    // A file index names a part that the data does not have.
+    MissingPart(String),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            SaveError::NotASave => write!(f, "A database can not be saved as a save file"),
+            SaveError::MissingPart(name) => write!(f, "'{name}' is not a filename"),
+        };
+    }
+}
+
 // Everything.
 #[derive(Default, Clone)]
 pub struct Data {
@@ -56,30 +74,30 @@ pub struct Data {
     officials: HashMap<i32, Official>,
     forenames: HashMap<i32, Name>,
     surnames: HashMap<i32, Name>,
-    cities: HashMap<i32, City>,
+    pub cities: HashMap<i32, City>,
     pub clubs: HashMap<i32, Club>,
-    nat_clubs: HashMap<i32, Club>,
+    pub nat_clubs: HashMap<i32, Club>,
     staff_awards: HashMap<i32, StaffAward>,
     pub competitions: HashMap<i32, Competition>,
-    nat_competitions: HashMap<i32, Competition>,
+    pub nat_competitions: HashMap<i32, Competition>,
     comp_history: HashMap<i32, CompetitionHistory>,
     nat_comp_history: HashMap<i32, CompetitionHistory>,
     colours: HashMap<i32, Colour>,
     pub nations: HashMap<i32, Nation>,
-    arenas: HashMap<i32, Arena>,
+    pub arenas: HashMap<i32, Arena>,
     pub staff: HashMap<i32, Staff>,
     nonplayers: Vec<(i32, NonPlayer)>, // Multiple IDs can exist?
     pub players: HashMap<i32, Player>,
     staff_preferences: HashMap<i32, StaffPreferences>,
     retired_numbers: HashMap<i32, RetiredNumber>,
-    states_provinces: HashMap<i32, StateProvince>,
-    injuries: HashMap<i16, Injury>,
-    currencies: HashMap<i32, Currency>,
-    drafts: HashMap<i32, Draft>,
+    pub states_provinces: HashMap<i32, StateProvince>,
+    pub injuries: HashMap<i16, Injury>,
+    pub currencies: HashMap<i32, Currency>,
+    pub drafts: HashMap<i32, Draft>,
     stage_names: HashMap<i32, StageName>,
 
     // Undecoded parts of the save file.
-    pub binaries: HashMap<String, Vec<u8>>,
+    pub binaries: HashMap<String, Binary>,
 
     // The order in which the entries should be saved.
     order_continents: Vec<i32>,
@@ -123,14 +141,9 @@ pub struct Data {
 }
 
 impl Data {
-    pub fn initialise(cursor: &mut Cursor<Vec<u8>>) -> Self {
-        let header = Header::read(cursor).unwrap();
-
-        let file_indexes = read_file_indexes(cursor, &header);
-        let data = Self {
-            _header: Some(header),
-            file_indexes,
-
+    // Get empty data with the rating boundaries ready to be calculated.
+    pub fn new() -> Self {
+        return Self {
             best_gk: usize::MIN,
             best_d: usize::MIN,
             best_w: usize::MIN,
@@ -145,238 +158,355 @@ impl Data {
 
             ..Default::default()
         };
+    }
+
+    pub fn initialise(cursor: &mut Cursor<&[u8]>) -> BinResult<Self> {
+        let header = Header::read(cursor)?;
+
+        let file_indexes = read_file_indexes(cursor, &header)?;
+        let data = Self {
+            _header: Some(header),
+            file_indexes,
+            ..Self::new()
+        };
 
-        return data;
+        return Ok(data);
     }
 
-    // Get a save file of the data.
-    pub fn _save_file(&mut self) -> Vec<u8> {
+    // Move the records parsed from one part of the save file into the data. The parts are merged in the order of
+    // the file indexes so that the records are saved in the same order.
+    pub fn merge(&mut self, mut part: Data) {
+        merge_map(&mut self.continents, part.continents);
+        merge_map(&mut self.officials, part.officials);
+        merge_map(&mut self.forenames, part.forenames);
+        merge_map(&mut self.surnames, part.surnames);
+        merge_map(&mut self.cities, part.cities);
+        merge_map(&mut self.clubs, part.clubs);
+        merge_map(&mut self.nat_clubs, part.nat_clubs);
+        merge_map(&mut self.staff_awards, part.staff_awards);
+        merge_map(&mut self.competitions, part.competitions);
+        merge_map(&mut self.nat_competitions, part.nat_competitions);
+        merge_map(&mut self.comp_history, part.comp_history);
+        merge_map(&mut self.nat_comp_history, part.nat_comp_history);
+        merge_map(&mut self.colours, part.colours);
+        merge_map(&mut self.nations, part.nations);
+        merge_map(&mut self.arenas, part.arenas);
+        merge_map(&mut self.staff, part.staff);
+        merge_map(&mut self.players, part.players);
+        merge_map(&mut self.staff_preferences, part.staff_preferences);
+        merge_map(&mut self.retired_numbers, part.retired_numbers);
+        merge_map(&mut self.states_provinces, part.states_provinces);
+        merge_map(&mut self.injuries, part.injuries);
+        merge_map(&mut self.currencies, part.currencies);
+        merge_map(&mut self.drafts, part.drafts);
+        merge_map(&mut self.stage_names, part.stage_names);
+        merge_map(&mut self.binaries, part.binaries);
+        self.nonplayers.append(&mut part.nonplayers);
+
+        self.order_continents.append(&mut part.order_continents);
+        self.order_officials.append(&mut part.order_officials);
+        self.order_forenames.append(&mut part.order_forenames);
+        self.order_surnames.append(&mut part.order_surnames);
+        self.order_cities.append(&mut part.order_cities);
+        self.order_clubs.append(&mut part.order_clubs);
+        self.order_nat_clubs.append(&mut part.order_nat_clubs);
+        self.order_staff_awards.append(&mut part.order_staff_awards);
+        self.order_competitions.append(&mut part.order_competitions);
+        self.order_nat_competitions.append(&mut part.order_nat_competitions);
+        self.order_comp_history.append(&mut part.order_comp_history);
+        self.order_nat_comp_history.append(&mut part.order_nat_comp_history);
+        self.order_colours.append(&mut part.order_colours);
+        self.order_nations.append(&mut part.order_nations);
+        self.order_arenas.append(&mut part.order_arenas);
+        self.order_staff.append(&mut part.order_staff);
+        self.order_players.append(&mut part.order_players);
+        self.order_staff_preferences.append(&mut part.order_staff_preferences);
+        self.order_retired_numbers.append(&mut part.order_retired_numbers);
+        self.order_states_provinces.append(&mut part.order_states_provinces);
+        self.order_injuries.append(&mut part.order_injuries);
+        self.order_currencies.append(&mut part.order_currencies);
+        self.order_drafts.append(&mut part.order_drafts);
+        self.order_stage_names.append(&mut part.order_stage_names);
+    }
+
+    // Check if the data was loaded from a database folder instead of a save file.
+    pub fn is_database(&self) -> bool {
+        return self._header.is_none();
+    }
+
+    // Find the players whose attributes the chart can not convert. A database stores them on the in-game scale.
+    pub fn check_attributes(&self) -> Vec<ConversionProblem> {
+        if self.is_database() {
+            return Vec::new();
+        }
+
+        return self.order_players.iter().flat_map(|id| self.players[id].conversion_problems()).collect();
+    }
+
+    // Get the players that match the filters.
+    pub fn filtered_players(&self, filters: &PlayerFilters) -> Vec<(&Staff, Player)> {
+        return self.staff.values()
+            .filter_map(|person| {
+                let player = person.player_data(self)?;
+                if !person.check_player_filters(self, filters) {
+                    return None;
+                }
+
+                return Some((person, player));
+            })
+            .collect();
+    }
+
+    // Get a save file of the data. Fails for data loaded from a database folder.
+    pub fn save_file(&mut self) -> Result<Vec<u8>, SaveError> {
+        let header = self._header.as_ref().ok_or(SaveError::NotASave)?.to_bytes();
+        let start_position = self.file_indexes.first().ok_or(SaveError::NotASave)?.start_position;
+
         // Encode all save data.
-        let mut encoded = self.binaries.clone();
+        let mut encoded: HashMap<String, Vec<u8>> = self.binaries.iter()
+            .map(|(name, binary)| (name.clone(), binary.bytes().to_vec()))
+            .collect();
+        encoded.extend(self.encode_files());
+
+        // Update the sizes of the save file parts and order them according to the file indexes.
+        let mut order = Vec::new();
+        for index in self.file_indexes.iter() {
+            let name = index.name();
+
+            // 'Steals' the contents of the vector.
+            let bin = match encoded.get_mut(&name) {
+                Some(b) => b,
+                None => return Err(SaveError::MissingPart(name)),
+            };
+
+            let bin = mem::take(bin);
+
+            let size = bin.len() as u32;
+            order.push((size, bin));
+        }
+
+        let mut total_size = start_position;
+
+        // Bundle the content into one byte array.
+        let mut content_bin = Vec::new();
+        for (i, (size, mut bin)) in order.into_iter().enumerate() {
+            let file_index = self.file_indexes.get_mut(i).unwrap();
+            content_bin.append(&mut bin);
+
+            file_index.start_position = total_size;
+            file_index.size = size;
+            total_size += size;
+        }
+
+        // Put the save file together.
+        let mut bin = header;
+        bin.append(
+            &mut self
+                .file_indexes
+                .iter()
+                .flat_map(|a| a.to_bytes())
+                .collect(),
+        );
+
+        // Pad the end of indexes and the start of content with NUL.
+        bin.resize(start_position as usize, 0);
+
+        bin.append(&mut content_bin);
+        return Ok(bin);
+    }
+
+    // Encode the decoded data into .dat files, keeping the original order of the records.
+    fn encode_files(&self) -> HashMap<String, Vec<u8>> {
+        let mut encoded = HashMap::new();
 
         encoded.insert(
             "continent.dat".to_string(),
             self.order_continents
                 .iter()
-                .flat_map(|id| self.continents.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.continents.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "officials.dat".to_string(),
             self.order_officials
                 .iter()
-                .flat_map(|id| self.officials.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.officials.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "first_names.dat".to_string(),
             self.order_forenames
                 .iter()
-                .flat_map(|id| self.forenames.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.forenames.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "second_names.dat".to_string(),
             self.order_surnames
                 .iter()
-                .flat_map(|id| self.surnames.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.surnames.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "city.dat".to_string(),
             self.order_cities
                 .iter()
-                .flat_map(|id| self.cities.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.cities.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "club.dat".to_string(),
             self.order_clubs
                 .iter()
-                .flat_map(|id| self.clubs.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.clubs.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "nat_club.dat".to_string(),
             self.order_nat_clubs
                 .iter()
-                .flat_map(|id| self.nat_clubs.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.nat_clubs.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "staff_comp.dat".to_string(),
             self.order_staff_awards
                 .iter()
-                .flat_map(|id| self.staff_awards.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.staff_awards.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "club_comp.dat".to_string(),
             self.order_competitions
                 .iter()
-                .flat_map(|id| self.competitions.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.competitions.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "nation_comp.dat".to_string(),
             self.order_nat_competitions
                 .iter()
-                .flat_map(|id| self.nat_competitions.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.nat_competitions.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "club_comp_history.dat".to_string(),
             self.order_comp_history
                 .iter()
-                .flat_map(|id| self.comp_history.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.comp_history.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "nation_comp_history.dat".to_string(),
             self.order_nat_comp_history
                 .iter()
-                .flat_map(|id| self.nat_comp_history.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.nat_comp_history.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "colour.dat".to_string(),
             self.order_colours
                 .iter()
-                .flat_map(|id| self.colours.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.colours.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "nation.dat".to_string(),
             self.order_nations
                 .iter()
-                .flat_map(|id| self.nations.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.nations.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "stadium.dat".to_string(),
             self.order_arenas
                 .iter()
-                .flat_map(|id| self.arenas.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.arenas.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "staff.dat".to_string(),
             self.order_staff
                 .iter()
-                .flat_map(|id| self.staff.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.staff.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "nonplayer.dat".to_string(),
             self.nonplayers
                 .iter()
-                .flat_map(|(_, a)| a._to_bytes())
+                .flat_map(|(_, a)| a.to_bytes())
                 .collect(),
         );
         encoded.insert(
             "player.dat".to_string(),
             self.order_players
                 .iter()
-                .flat_map(|id| self.players.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.players.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "staff_preferences.dat".to_string(),
             self.order_staff_preferences
                 .iter()
-                .flat_map(|id| self.staff_preferences.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.staff_preferences.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "retired_numbers.dat".to_string(),
             self.order_retired_numbers
                 .iter()
-                .flat_map(|id| self.retired_numbers.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.retired_numbers.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "states_provinces.dat".to_string(),
             self.order_states_provinces
                 .iter()
-                .flat_map(|id| self.states_provinces.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.states_provinces.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "injuries.dat".to_string(),
             self.order_injuries
                 .iter()
-                .flat_map(|id| self.injuries.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.injuries.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "currencies.dat".to_string(),
             self.order_currencies
                 .iter()
-                .flat_map(|id| self.currencies.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.currencies.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "drafts.dat".to_string(),
             self.order_drafts
                 .iter()
-                .flat_map(|id| self.drafts.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.drafts.get(id).unwrap().to_bytes())
                 .collect(),
         );
         encoded.insert(
             "stage_names.dat".to_string(),
             self.order_stage_names
                 .iter()
-                .flat_map(|id| self.stage_names.get(id).unwrap()._to_bytes())
+                .flat_map(|id| self.stage_names.get(id).unwrap().to_bytes())
                 .collect(),
         );
 
-        // Update the sizes of the save file parts and order them according to the file indexes.
-        let mut order = Vec::new();
-        for index in self.file_indexes.iter() {
-            let name = index.name();
-
-            // 'Steals' the contents of the vector.
-            let bin = match encoded.get_mut(&name) {
-                Some(b) => b,
-                None => panic!("'{name}' is not a filename."),
-            };
-
-            let bin = mem::take(bin);
-
-            let size = bin.len() as u32;
-            order.push((size, bin));
-        }
-
-        // Get the start position of the first file.
-        let start_position = self.file_indexes.get(0).unwrap().start_position;
-        let mut total_size = start_position;
+        return encoded;
+    }
 
-        // Bundle the content into one byte array.
-        let mut content_bin = Vec::new();
-        for (i, (size, mut bin)) in order.into_iter().enumerate() {
-            let file_index = self.file_indexes.get_mut(i).unwrap();
-            content_bin.append(&mut bin);
+    // Write the decoded data into a folder as an EHM database.
+    pub fn save_database_folder(&self, path: &Path) -> io::Result<()> {
+        fs::create_dir_all(path)?;
 
-            file_index.start_position = total_size;
-            file_index.size = size;
-            total_size += size;
+        for (filename, bin) in self.encode_files() {
+            fs::write(path.join(filename), bin)?;
         }
 
-        // Put the save file together.
-        let mut bin = self._header.as_ref().unwrap()._to_bytes();
-        bin.append(
-            &mut self
-                .file_indexes
-                .iter()
-                .flat_map(|a| a._to_bytes())
-                .collect(),
-        );
-
-        // Pad the end of indexes and the start of content with NUL.
-        bin.resize(start_position as usize, 0);
-
-        bin.append(&mut content_bin);
-        return bin;
+        return Ok(());
     }
 
     // Determine what the in-game date could be.
@@ -389,27 +519,9 @@ impl Data {
             let (min_date, max_date) = staff.dates_with_this_age();
             if min_date > self.date_range[0] && min_date <= self.date_range[1] {
                 self.date_range[0] = min_date;
-
-                println!(
-                    "{} - {}\n{}, {}, {}\n",
-                    self.date_range[0].to_string(),
-                    self.date_range[1].to_string(),
-                    staff.full_name(self),
-                    staff.age,
-                    staff.date_of_birth.to_string(),
-                );
             }
             if max_date < self.date_range[1] && max_date >= self.date_range[0] {
                 self.date_range[1] = max_date;
-
-                println!(
-                    "{} - {}\n{}, {}, {}\n",
-                    self.date_range[0].to_string(),
-                    self.date_range[1].to_string(),
-                    staff.full_name(self),
-                    staff.age,
-                    staff.date_of_birth.to_string(),
-                );
             }
 
             // The date has been determined when the dates are equal.
@@ -417,8 +529,6 @@ impl Data {
                  break;
             }
         }
-
-        println!("\n");
     }
 
     // Calculate the best and the worst player ratings the save file has.
@@ -451,18 +561,21 @@ impl Data {
                 if c < self.worst_c { self.worst_c = c; }
             }
 
-
         }
     }
 
 }
 
-#[derive(BinRead, PartialEq, Clone)]
-#[br(little)]
-pub struct SIDate {
-    day: i16,
-    pub year: i16,
-    b_is_leap_year: u8,
+// A date as year, month and day.
+pub type YearMonthDay = (i16, u8, u8);
+
+record! {
+    #[derive(PartialEq, Clone)]
+    pub struct SIDate {
+        day: i16,
+        pub year: i16,
+        b_is_leap_year: u8,
+    }
 }
 
 impl Default for SIDate {
@@ -520,6 +633,24 @@ impl SIDate {
         return date;
     }
 
+    // Get an instance from year, month and day. Return None if there is no such date.
+    pub fn from_year_month_day(year: i16, month: u8, month_day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || month_day == 0 {
+            return None;
+        }
+
+        let days_in_month = match month == 2 && is_leap_year(year as i32) {
+            true => 29,
+            false => Self::MONTH_DAYS[month as usize - 1],
+        };
+
+        if month_day > days_in_month {
+            return None;
+        }
+
+        return Some(Self::_new_from_date(year, month, month_day));
+    }
+
     // Get an instance from year, month and day.
     fn _new_from_date(year: i16, month: u8, month_day: u8) -> Self {
         let days_in_february = if is_leap_year(year as i32) { 29 } else { 28 };
@@ -534,7 +665,7 @@ impl SIDate {
         }
 
         day += month_day as i16 - 1;
-        return Self { year, day, b_is_leap_year: 0 };
+        return Self { year, day, b_is_leap_year: is_leap_year(year as i32) as u8 };
     }
 
     // SI bullshit.
@@ -547,30 +678,10 @@ impl SIDate {
         return is_leap_year(self.year as i32);
     }
 
-    fn _is_default(&self) -> bool {
+    fn is_default(&self) -> bool {
         return self.day == 31 && self.year == 1900;
     }
 
-    fn _to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::new();
-
-        bytes.extend_from_slice(&self.day.to_le_bytes());
-        bytes.extend_from_slice(&self.year.to_le_bytes());
-        bytes.extend_from_slice(&self.b_is_leap_year.to_le_bytes());
-
-        return bytes;
-    }
-
-    fn to_string(&self) -> String {
-        return format!(
-            "({}, {}, {}, {})",
-            self.year,
-            self.day,
-            is_leap_year(self.year as i32),
-            self.b_is_leap_year,
-        );
-    }
-
     // Get days since the default (1.2.1900).
     fn _to_days(&self) -> usize {
         return self._days_between(Self::default());
@@ -600,6 +711,15 @@ impl SIDate {
         return (self.year, month, days as u8 + 1);
     }
 
+    // Get the year, month and day of the date, unless the date has not been set.
+    pub fn to_optional_year_month_day(&self) -> Option<(i16, u8, u8)> {
+        if self.is_default() {
+            return None;
+        }
+
+        return Some(self.to_year_month_day());
+    }
+
     // Get days between this date and another. This date must be more recent.
     fn _days_between(&self, other: Self) -> usize {
         // Add days from the earlier date's year.
@@ -665,15 +785,27 @@ impl SIDate {
     }
 }
 
-// Convert an attribute from save file to in-game.
-pub fn convert_attribute(current_ability: i16, attribute: i8) -> i8 {
-    let ca_chart = ATTRIBUTE_CHART.get(&current_ability).unwrap();
+// Check if the year has a 29th of February in the Gregorian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    return year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+}
 
-    for (real_attr, range) in ca_chart {
-        if range.contains(&attribute) {
-            return *real_attr;
-        }
+// Add the entries of one map to another. Move the whole map if the other one is empty.
+fn merge_map<K: Eq + Hash, V>(into: &mut HashMap<K, V>, from: HashMap<K, V>) {
+    match into.is_empty() {
+        true => *into = from,
+        false => into.extend(from),
     }
+}
+
+// Convert an attribute from save file to in-game. None if the chart has no range for it.
+pub fn try_convert_attribute(current_ability: i16, attribute: i8) -> Option<i8> {
+    let ca_chart = ATTRIBUTE_CHART.get(&current_ability)?;
+
+    return ca_chart.iter().find(|(_, range)| range.contains(&attribute)).map(|(real_attr, _)| *real_attr);
+}
 
-    panic!("current ability '{current_ability}' does not have save-file attribute '{attribute}'");
+// Get the IDs of a fixed-size ID list, leaving out the empty (-1) slots.
+pub fn set_ids(ids: &[i32]) -> Vec<i32> {
+    return ids.iter().filter(|id| **id != -1).copied().collect();
 }
@@ -0,0 +1,87 @@
+use std::{collections::HashMap, io::Cursor};
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::{EncodeError, bytes_to_string, string_to_si_bytes}, data::{Data, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Name {
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        pub b_name: Vec<u8>,
+        id: i32,
+        nation_id: i32,
+        count: i8,
+    }
+}
+
+impl Name {
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    // Get the ID of a name with the text, or add a new name if there is none.
+    // The count of people with the name is kept up to date.
+    pub fn use_name(
+        names: &mut HashMap<i32, Name>,
+        order: &mut Vec<i32>,
+        old_id: i32,
+        text: &str,
+        nation_id: i32,
+    ) -> Result<i32, EncodeError> {
+        let b_name = string_to_si_bytes(text, STANDARD_TEXT_LENGTH)?;
+
+        // Prefer a name of the person's nation when the same name exists for many.
+        let existing = order.iter()
+            .map(|id| names.get(id).unwrap())
+            .filter(|n| n.name() == text)
+            .max_by_key(|n| n.nation_id == nation_id)
+            .map(|n| n.id);
+
+        let id = match existing {
+            Some(id) => id,
+            None => {
+                let id = order.iter().max().map_or(0, |id| id + 1);
+                order.push(id);
+                names.insert(id, Name { b_name, id, nation_id, count: 0 });
+                id
+            }
+        };
+
+        if id != old_id {
+            if let Some(old) = names.get_mut(&old_id) {
+                old.count = old.count.saturating_sub(1).max(0);
+            }
+
+            let name = names.get_mut(&id).unwrap();
+            name.count = name.count.saturating_add(1);
+        }
+
+        return Ok(id);
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+        ]);
+    }
+
+    pub fn parse_forename(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let name = Self::read(cursor)?;
+        data.order_forenames.push(name.id);
+        data.forenames.insert(name.id, name);
+
+        return Ok(());
+    }
+
+    pub fn parse_surname(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let name = Self::read(cursor)?;
+        data.order_surnames.push(name.id);
+        data.surnames.insert(name.id, name);
+
+        return Ok(());
+    }
+}
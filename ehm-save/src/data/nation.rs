@@ -0,0 +1,131 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, SHORT_TEXT_LENGTH, STANDARD_TEXT_LENGTH, THREE_LETTER_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct Nation {
+        _iihf_ranking_score: f64,
+        _iihf_ranking_score_98: f64,
+        _iihf_ranking_score_99: f64,
+        _iihf_ranking_score_00: f64,
+        _iihf_ranking_score_01: f64,
+        _iihf_ranking_score_02: f64,
+        _iihf_ranking_score_03: f64,
+        _jr_final_position_1: i8,
+        _jr_final_position_2: i8,
+        _jr_final_position_3: i8,
+        _men_final_position_1: i8,
+        _men_final_position_2: i8,
+        _men_final_position_3: i8,
+        pub id: i32,
+        _number_staff: i32,
+        continent_id: i32,
+        capital_id: i32,
+        pub national_stadium_id: i32,
+        foreground_colour1_id: i32,
+        background_colour1_id: i32,
+        trim_colour1_id: i32,
+        foreground_colour2_id: i32,
+        background_colour2_id: i32,
+        trim_colour2_id: i32,
+        foreground_colour3_id: i32,
+        background_colour3_id: i32,
+        trim_colour3_id: i32,
+        rival1_id: i32,
+        rival2_id: i32,
+        rival3_id: i32,
+        b_three_letter_name: [u8; THREE_LETTER_TEXT_LENGTH as usize],
+        _number_clubs: i16,
+        _reputation: i16,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        b_name: Vec<u8>,
+        b_short_name: [u8; SHORT_TEXT_LENGTH as usize],
+        b_nationality_name: [u8; SHORT_TEXT_LENGTH as usize],
+        _gender_name: i8,
+        _gender_name_short: i8,
+        _region: i8,
+        _actual_region: i8,
+        _first_language: i8,
+        _second_language: i8,
+        _third_language: i8,
+        _state_of_development: i8,
+        _group_membership: i8,
+        _game_importance: i8,
+        _league_standard: i8,
+        _league_selected: i8,
+        _games_played: i8,
+        _citizenship_years: i8,
+    }
+}
+
+impl Nation {
+    pub fn _three_letter_name(&self) -> String {
+        return bytes_to_string(&self.b_three_letter_name);
+    }
+
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    pub fn _short_name(&self) -> String {
+        return bytes_to_string(&self.b_short_name);
+    }
+
+    fn _nationality_name(&self) -> String {
+        return bytes_to_string(&self.b_nationality_name);
+    }
+
+    // Get the text fields that can be renamed.
+    pub fn text_fields(&self) -> Vec<(&'static str, &[u8])> {
+        return Vec::from([
+            ("Name", self.b_name.as_slice()),
+            ("Short Name", self.b_short_name.as_slice()),
+            ("Nationality Name", self.b_nationality_name.as_slice()),
+            ("Three Letter Name", self.b_three_letter_name.as_slice()),
+        ]);
+    }
+
+    pub fn text_field_mut(&mut self, field: &str) -> Option<&mut [u8]> {
+        return match field {
+            "Name" => Some(&mut self.b_name),
+            "Short Name" => Some(&mut self.b_short_name),
+            "Nationality Name" => Some(&mut self.b_nationality_name),
+            "Three Letter Name" => Some(&mut self.b_three_letter_name),
+            _ => None,
+        };
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Continent", Table::Continents, self.continent_id),
+            ("Capital", Table::Cities, self.capital_id),
+            ("National Arena", Table::Arenas, self.national_stadium_id),
+            ("Foreground Colour 1", Table::Colours, self.foreground_colour1_id),
+            ("Background Colour 1", Table::Colours, self.background_colour1_id),
+            ("Trim Colour 1", Table::Colours, self.trim_colour1_id),
+            ("Foreground Colour 2", Table::Colours, self.foreground_colour2_id),
+            ("Background Colour 2", Table::Colours, self.background_colour2_id),
+            ("Trim Colour 2", Table::Colours, self.trim_colour2_id),
+            ("Foreground Colour 3", Table::Colours, self.foreground_colour3_id),
+            ("Background Colour 3", Table::Colours, self.background_colour3_id),
+            ("Trim Colour 3", Table::Colours, self.trim_colour3_id),
+            ("Rival 1", Table::Nations, self.rival1_id),
+            ("Rival 2", Table::Nations, self.rival2_id),
+            ("Rival 3", Table::Nations, self.rival3_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let nation = Self::read(cursor)?;
+        data.order_nations.push(nation.id);
+        data.nations.insert(nation.id, nation);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{data::Data, record::record};
+
+record! {
+    #[derive(Clone)]
+    pub struct NonPlayer {
+        id: i32,
+        _current_ability: i16,
+        _potential_ability: i16,
+        _home_reputation: i16,
+        _current_reputation: i16,
+        _world_reputation: i16,
+        _attacking: i8,
+        _business: i8,
+        _coaching_technique: i8,
+        _directness: i8,
+        _discipline: i8,
+        _free_roles: i8,
+        _interference: i8,
+        _judgement: i8,
+        _judging_potential: i8,
+        _man_handling: i8,
+        _motivating: i8,
+        _patience: i8,
+        _physiotherapy: i8,
+        _resources: i8,
+        _tactics: i8,
+        _youngsters: i8,
+        _tactic_preferred: i8,
+        _coaching_forwards: i8,
+        _coaching_defensemen: i8,
+        _coaching_goaltenders: i8,
+        _line_matching: i8,
+        _power_play: i8,
+        _penalty_kill: i8,
+        _physical: i8,
+    }
+}
+
+impl NonPlayer {
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let nonplayer = Self::read(cursor)?;
+        data.nonplayers.push((nonplayer.id, nonplayer));
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,50 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{data::{Data, SIDate, integrity::{Reference, Table}}, record::record};
+
+record! {
+    #[derive(Clone)]
+    pub struct Official {
+        id: i32,
+        forename_id: i32,
+        surname_id: i32,
+        nation_id: i32,
+        city_id: i32,
+        comp_id: i32,
+        _year_of_birth: i16,
+        _current_ability: i16,
+        _potential_ability: i16,
+        _reputation: i16,
+        _date_of_birth: SIDate,
+        _allowing_roughness: i8,
+        _discipline: i8,
+        _important_matches: i8,
+        _pressure: i8,
+        _refereeing: i8,
+        _skating_line: i8,
+        _allowing_interference: i8,
+    }
+}
+
+impl Official {
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Forename", Table::Forenames, self.forename_id),
+            ("Surname", Table::Surnames, self.surname_id),
+            ("Nation", Table::Nations, self.nation_id),
+            ("City", Table::Cities, self.city_id),
+            ("Competition", Table::Competitions, self.comp_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let official = Self::read(cursor)?;
+        data.order_officials.push(official.id);
+        data.officials.insert(official.id, official);
+
+        return Ok(());
+    }
+}
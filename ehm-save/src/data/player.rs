@@ -0,0 +1,252 @@
+use std::{collections::HashMap, io::Cursor};
+use binread::{BinRead, Error};
+
+use crate::{
+    data::{Data, chart_check::ConversionProblem, try_convert_attribute},
+    globals::attr_chart::ATTRIBUTE_CHART, rating, record::record
+};
+
+// The attributes that are stored in the save file on another scale than in-game.
+pub const CONVERTED_ATTRIBUTES: [&str; 23] = [
+    "Anticipation",
+    "Balance",
+    "Decisions",
+    "Off The Puck",
+    "One On Ones",
+    "Passing",
+    "Positioning",
+    "Reflexes",
+    "Creativity",
+    "Blocker",
+    "Checking",
+    "Deflections",
+    "Deking",
+    "Faceoffs",
+    "Fighting",
+    "Glove",
+    "Hitting",
+    "Pokecheck",
+    "Rebound Control",
+    "Recovery",
+    "Slapshot",
+    "Stickhandling",
+    "Wristshot",
+];
+
+record! {
+    #[derive(Clone, Default)]
+    pub struct Player {
+        pub id: i32,
+        pub current_ability: i16,
+        pub potential_ability: i16,
+        pub home_reputation: i16,
+        pub current_reputation: i16,
+        pub world_reputation: i16,
+        pub squad_number: i8,
+        pub international_squad_number: i8,
+        pub acceleration: i8,
+        pub aggression: i8,
+        pub agility: i8,
+        pub anticipation_raw: i8,
+        pub balance_raw: i8,
+        pub bravery: i8,
+        pub consistency: i8,
+        pub decisions_raw: i8,
+        pub dirtiness: i8,
+        pub flair: i8,
+        pub important_matches: i8,
+        pub injury_proneness: i8,
+        pub leadership: i8,
+        pub movement_raw: i8,
+        pub natural_fitness: i8,
+        pub one_on_ones_raw: i8,
+        pub pace: i8,
+        pub passing_raw: i8,
+        pub positioning_raw: i8,
+        pub reflexes_raw: i8,
+        pub stamina: i8,
+        pub strength: i8,
+        pub teamwork: i8,
+        pub versatility: i8,
+        pub vision_raw: i8,
+        pub work_rate: i8,
+        pub handedness: i8,
+        pub height: u8,
+        pub weight: u8,
+        pub favourite_number: u8,
+        pub goaltender: i8,
+        pub left_defence: i8,
+        pub right_defence: i8,
+        pub left_wing: i8,
+        pub center: i8,
+        pub right_wing: i8,
+        pub agitation: i8,
+        pub blocker_raw: i8,
+        pub checking_raw: i8,
+        pub defensive_role: i8,
+        pub deflections_raw: i8,
+        pub deking_raw: i8,
+        pub faceoffs_raw: i8,
+        pub fighting_raw: i8,
+        pub glove_raw: i8,
+        pub hitting_raw: i8,
+        pub offensive_role: i8,
+        pub pass_tendency: i8,
+        pub pokecheck_raw: i8,
+        pub rebounds_raw: i8,
+        pub recovery_raw: i8,
+        pub slapshot_raw: i8,
+        pub stickhandling_raw: i8,
+        pub wristshot_raw: i8,
+        pub morale: i8,
+        pub goalie_style: i8,
+        pub junior_preference: i8,
+    }
+}
+
+impl Player {
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let player = Self::read(cursor)?;
+        data.order_players.push(player.id);
+        data.players.insert(player.id, player);
+
+        return Ok(());
+    }
+
+    // Get the positions of the player as a vector.
+    pub fn position_vec(&self) -> Vec<u8> {
+        let mut positions = Vec::from([
+            (0, self.goaltender),
+            (1, self.left_defence),
+            (2, self.right_defence),
+            (3, self.left_wing),
+            (4, self.center),
+            (5, self.right_wing),
+        ]);
+
+        positions.sort_by(|a, b| b.1.cmp(&a.1));
+
+        return positions.into_iter()
+            .filter_map(|(pos, score)| {
+                if score < 16 {
+                    return None;
+                }
+                else {
+                    return Some(pos);
+                }
+            })
+            .collect();
+    }
+
+    pub fn position_string(&self) -> String {
+        let positions = HashMap::from([
+            (0, "G"),
+            (1, "LD"),
+            (2, "RD"),
+            (3, "LW"),
+            (4, "C"),
+            (5, "RW"),
+        ]);
+
+        let player_positions = self.position_vec();
+
+        let string: Vec<&str> = player_positions.into_iter()
+            .map(|pos| *positions.get(&pos).unwrap())
+            .collect();
+
+        return string.join("/");
+    }
+
+    pub fn is_goalie(&self) -> bool {
+        return self.goaltender == 20;
+    }
+
+    // Get the save file value of an attribute that has to be converted to be shown in-game.
+    pub fn raw_attribute(&self, attr_name: &str) -> i8 {
+        return match attr_name {
+            "Anticipation" => self.anticipation_raw,
+            "Balance" => self.balance_raw,
+            "Decisions" => self.decisions_raw,
+            "Off The Puck" => self.movement_raw,
+            "One On Ones" => self.one_on_ones_raw,
+            "Passing" => self.passing_raw,
+            "Positioning" => self.positioning_raw,
+            "Reflexes" => self.reflexes_raw,
+            "Creativity" => self.vision_raw,
+            "Blocker" => self.blocker_raw,
+            "Checking" => self.checking_raw,
+            "Deflections" => self.deflections_raw,
+            "Deking" => self.deking_raw,
+            "Faceoffs" => self.faceoffs_raw,
+            "Fighting" => self.fighting_raw,
+            "Glove" => self.glove_raw,
+            "Hitting" => self.hitting_raw,
+            "Pokecheck" => self.pokecheck_raw,
+            "Rebound Control" => self.rebounds_raw,
+            "Recovery" => self.recovery_raw,
+            "Slapshot" => self.slapshot_raw,
+            "Stickhandling" => self.stickhandling_raw,
+            "Wristshot" => self.wristshot_raw,
+            _ => panic!("{attr_name} is not an attribute"),
+        };
+    }
+
+    // Convert an attribute to in-game. None if the chart can not convert it, see `conversion_problems`.
+    pub fn convert_attribute(&self, attr_name: &str) -> Option<i8> {
+        return try_convert_attribute(self.current_ability, self.raw_attribute(attr_name));
+    }
+
+    // Find the attributes the chart can not convert.
+    pub fn conversion_problems(&self) -> Vec<ConversionProblem> {
+        if !ATTRIBUTE_CHART.contains_key(&self.current_ability) {
+            return vec![ConversionProblem::UnknownCa { player_id: self.id, ca: self.current_ability }];
+        }
+
+        return CONVERTED_ATTRIBUTES.iter()
+            .filter(|attribute| try_convert_attribute(self.current_ability, self.raw_attribute(attribute)).is_none())
+            .map(|attribute| ConversionProblem::UnconvertibleValue {
+                player_id: self.id,
+                ca: self.current_ability,
+                attribute,
+                raw: self.raw_attribute(attribute),
+            })
+            .collect();
+    }
+
+    // Get the rating of a player.
+    pub fn rating(&self, _data: &Data, score: usize, low: usize, high: usize) -> f64 {
+        let attribute_rating = rating::stretch(score, low, high);
+
+        //let ca = self.current_ability as usize;
+        // let ca_rating = rating::stretch(ca, data.worst_ca as usize, data.best_ca as usize);
+
+        // let combined_rating = (attribute_rating + ca_rating) / 2.0;
+        // return rating::restrict_minimum(combined_rating * self.consistency_rating(), 0.01);
+
+        return rating::restrict_minimum(attribute_rating * self.consistency_rating(), 0.01);
+    }
+
+    // Get a 'consistency rating' for the player.
+    fn consistency_rating(&self) -> f64 {
+        let score = (self.consistency * 2) + self.important_matches + self.work_rate - 4;
+        let perfect_score = (40 + 20 + 20 - 4) as f64;
+
+        return rating::restrict_minimum(score as f64 / perfect_score, 0.5);
+    }
+
+    // Get a position rating for the player.
+    pub fn position_rating(&self, mut position: i8) -> f64 {
+        if position == 20 {
+            return 1.0;
+        }
+
+        if position == 0 {
+            position = 1;
+        }
+
+        let score = (position * 2) + self.versatility - 3;
+        let perfect_score = (40 + 20 - 3) as f64;
+
+        return rating::restrict_minimum(score as f64 / perfect_score, 0.5);
+    }
+}
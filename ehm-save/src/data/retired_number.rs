@@ -0,0 +1,39 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, LONG_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct RetiredNumber {
+        id: i32,
+        club_id: i32,
+        #[br(count = LONG_TEXT_LENGTH)]
+        _b_player_name: Vec<u8>,
+        _number: u8,
+    }
+}
+
+impl RetiredNumber {
+    fn _player_name(&self) -> String {
+        return bytes_to_string(&self._b_player_name);
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Club", Table::Clubs, self.club_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let number = Self::read(cursor)?;
+        data.order_retired_numbers.push(number.id);
+        data.retired_numbers.insert(number.id, number);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,963 @@
+use std::{collections::HashMap, io::Cursor};
+
+use binread::{BinRead, Error};
+use regex::Regex;
+
+use crate::{
+    chars::{EncodeError, string_to_si_bytes}, filters::PlayerFilters, money, data::{Data, SIDate, STANDARD_TEXT_LENGTH, city::City, club::Club, currency::Currency, integrity::{Reference, Table}, name::Name, nation::Nation, player::Player, staff_preferences::StaffPreferences}, research::db, scripts::{Scripts, player_scope}, views, record::record
+};
+
+// The attributes of a position score, grouped by their weight.
+pub type ScoreTiers = [(usize, &'static [&'static str]); 4];
+
+// The weight of a tier and its attributes by name. None for an attribute the chart can not convert.
+pub type AttributeTier = (usize, Vec<(&'static str, Option<i8>)>);
+
+pub const GK_SCORE_TIERS: ScoreTiers = [
+    (30, &["Agility", "Bravery", "Determination", "Glove", "Recovery", "Reflexes"]),
+    (20, &["Blocker", "One On Ones", "Positioning", "Rebound Control", "Stamina"]),
+    (10, &[
+        "Balance", "Passing", "Pokecheck", "Pressure", "Professionalism", "Sportsmanship", "Stickhandling",
+        "Temperament",
+    ]),
+    (1, &[
+        "Acceleration", "Agitation", "Anticipation", "Decisions", "Flair", "Influence", "Natural Fitness", "Speed",
+        "Strength", "Teamwork",
+    ]),
+];
+
+pub const D_SCORE_TIERS: ScoreTiers = [
+    (30, &[
+        "Acceleration", "Anticipation", "Bravery", "Checking", "Determination", "Hitting", "Passing", "Pokecheck",
+        "Positioning", "Pressure", "Slapshot", "Speed", "Teamwork",
+    ]),
+    (20, &["Balance", "Creativity", "Decisions", "Flair", "Off The Puck", "Stamina", "Stickhandling", "Strength"]),
+    (10, &["Agility", "Agitation", "Professionalism", "Sportsmanship", "Temperament", "Wristshot"]),
+    (1, &["Deflections", "Deking", "Influence", "Natural Fitness"]),
+];
+
+pub const C_SCORE_TIERS: ScoreTiers = [
+    (30, &[
+        "Acceleration", "Anticipation", "Creativity", "Determination", "Faceoffs", "Passing", "Pressure", "Speed",
+        "Stickhandling", "Strength", "Wristshot",
+    ]),
+    (20, &[
+        "Agility", "Balance", "Bravery", "Checking", "Decisions", "Deflections", "Deking", "Flair", "Hitting",
+        "Off The Puck", "Pokecheck", "Positioning", "Stamina", "Teamwork",
+    ]),
+    (10, &["Agitation", "Professionalism", "Slapshot", "Sportsmanship", "Temperament"]),
+    (1, &["Influence", "Natural Fitness"]),
+];
+
+pub const W_SCORE_TIERS: ScoreTiers = [
+    (30, &[
+        "Acceleration", "Agility", "Anticipation", "Determination", "Passing", "Pressure", "Speed", "Stickhandling",
+        "Wristshot",
+    ]),
+    (20, &[
+        "Balance", "Bravery", "Creativity", "Decisions", "Deking", "Flair", "Off The Puck", "Positioning", "Stamina",
+        "Strength", "Teamwork",
+    ]),
+    (10, &[
+        "Agitation", "Checking", "Deflections", "Hitting", "Pokecheck", "Professionalism", "Slapshot", "Sportsmanship",
+        "Temperament",
+    ]),
+    (1, &["Influence", "Natural Fitness"]),
+];
+
+record! {
+    #[derive(Clone, Default)]
+    pub struct Staff {
+        pub id: i32,
+        estimated_wage: i32,
+        estimated_value: i32,
+        nation_id: i32,
+        second_nation_id: i32,
+        player_data_id: i32,
+        preferences_id: i32,
+        non_player_data_id: i32,
+        _nation_contracted_id: i32,
+        pub club_contracted_id: i32,
+        pub club_playing_id: i32,
+        player_rights_index: i32,
+        birth_town_id: i32,
+        forename_id: i32,
+        surname_id: i32,
+        pub date_of_birth: SIDate,
+        date_joined_nation: SIDate,
+        contract_expires_nation: SIDate,
+        date_joined_club: SIDate,
+        contract_expires_club: SIDate,
+        first_pro_contract: SIDate,
+        pub age: i16,
+        international_apps: u8,
+        international_goals: u8,
+        international_assists: u8,
+        _job_for_nation: i8,
+        pub adaptability: i8,
+        _job_for_club: i8,
+        pub ambition: i8,
+        pub determination: i8,
+        pub loyalty: i8,
+        pub pressure: i8,
+        pub professionalism: i8,
+        pub sportsmanship: i8,
+        pub temperament: i8,
+        _playing_squad: i8,
+        _classification: i8,
+        _club_valuation: i8,
+        declared_nation: i8,
+        stanley_cups_won: i8,
+        _squad_selected_for: i8,
+        _national_team_job_level: i8,
+        estimated_wage_weekly: i32,
+    }
+}
+
+impl Staff {
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+            ("Second Nation", Table::Nations, self.second_nation_id),
+            ("Player Data", Table::Players, self.player_data_id),
+            ("Preferences", Table::StaffPreferences, self.preferences_id),
+            ("Non-Player Data", Table::NonPlayers, self.non_player_data_id),
+            ("Club Contracted", Table::Clubs, self.club_contracted_id),
+            ("Club Playing", Table::Clubs, self.club_playing_id),
+            ("Birth Town", Table::Cities, self.birth_town_id),
+            ("Forename", Table::Forenames, self.forename_id),
+            ("Surname", Table::Surnames, self.surname_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let staff = Self::read(cursor)?;
+        data.order_staff.push(staff.id);
+        data.staff.insert(staff.id, staff);
+
+        return Ok(());
+    }
+
+    pub fn forename(&self, data: &Data) -> String {
+        return data.forenames.get(&self.forename_id).unwrap().name();
+    }
+
+    pub fn surname(&self, data: &Data) -> String {
+        return data.surnames.get(&self.surname_id).unwrap().name();
+    }
+
+    // Rename the person, reusing the names already in the save where possible.
+    // Both names are validated before anything is changed.
+    pub fn rename(data: &mut Data, staff_id: i32, forename: &str, surname: &str) -> Result<(), EncodeError> {
+        string_to_si_bytes(forename, STANDARD_TEXT_LENGTH)?;
+        string_to_si_bytes(surname, STANDARD_TEXT_LENGTH)?;
+
+        let person = data.staff.get(&staff_id).unwrap();
+        let (nation_id, forename_id, surname_id) = (person.nation_id, person.forename_id, person.surname_id);
+
+        let forename_id = Name::use_name(&mut data.forenames, &mut data.order_forenames, forename_id, forename, nation_id)?;
+        let surname_id = Name::use_name(&mut data.surnames, &mut data.order_surnames, surname_id, surname, nation_id)?;
+
+        let person = data.staff.get_mut(&staff_id).unwrap();
+        person.forename_id = forename_id;
+        person.surname_id = surname_id;
+
+        return Ok(());
+    }
+
+    pub fn _forename_object(&self, data: &Data) -> Name {
+        return data.forenames.get(&self.forename_id).unwrap().clone();
+    }
+
+    pub fn _surname_object(&self, data: &Data) -> Name {
+        return data.surnames.get(&self.surname_id).unwrap().clone();
+    }
+
+    pub fn birth_town(&self, data: &Data) -> Option<City> {
+        return data.cities.get(&self.birth_town_id).cloned();
+    }
+
+    pub fn birthplace(&self, data: &Data) -> String {
+        let town = self.birth_town(data);
+        if town.is_none() {
+            return String::new();
+        }
+        let town = town.unwrap();
+
+        let state_string = match town.state_abbreviation(data) {
+            Some(s) => format!(", {s}"),
+            None => String::new(),
+        };
+
+        let nation_string = match town.nation_three_letter_name(data) {
+            Some(s) => format!(", {s}"),
+            None => String::new(),
+        };
+
+        let town_name = town._name();
+
+        return format!("{}{}{}", town_name, state_string, nation_string);
+    }
+
+    fn _nation(&self, data: &Data) -> Nation {
+        return data.nations.get(&self.nation_id).cloned().unwrap();
+    }
+
+    pub fn nation_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.nation_id) {
+            Some(n) => n.name(),
+            None => String::new(),
+        };
+    }
+
+    fn _nation_short_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.nation_id) {
+            Some(n) => n._short_name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn _nation_three_letter_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.nation_id) {
+            Some(n) => n._three_letter_name(),
+            None => String::new(),
+        };
+    }
+
+    fn _second_nation(&self, data: &Data) -> Option<Nation> {
+        return data.nations.get(&self.second_nation_id).cloned();
+    }
+
+    pub fn second_nation_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.second_nation_id) {
+            Some(n) => n.name(),
+            None => String::new(),
+        };
+    }
+
+    fn _second_nation_short_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.second_nation_id) {
+            Some(n) => n._short_name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn _second_nation_three_letter_name(&self, data: &Data) -> String {
+        return match data.nations.get(&self.second_nation_id) {
+            Some(n) => n._three_letter_name(),
+            None => String::new(),
+        };
+    }
+
+    // Move the person to the clubs. The clubs differ when the person is on loan.
+    pub fn set_clubs(&mut self, club_contracted_id: i32, club_playing_id: i32, date_joined: SIDate, contract_expires: SIDate) {
+        self.club_contracted_id = club_contracted_id;
+        self.club_playing_id = club_playing_id;
+        self.date_joined_club = date_joined;
+        self.contract_expires_club = contract_expires;
+    }
+
+    pub fn club_contracted(&self, data: &Data) -> Option<Club> {
+        return data.clubs.get(&self.club_contracted_id).cloned();
+    }
+
+    pub fn club_contracted_name(&self, data: &Data) -> String {
+        return match data.clubs.get(&self.club_contracted_id) {
+            Some(c) => c.name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn _club_contracted_short_name(&self, data: &Data) -> String {
+        return match data.clubs.get(&self.club_contracted_id) {
+            Some(c) => c._short_name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn club_playing(&self, data: &Data) -> Option<Club> {
+        return data.clubs.get(&self.club_playing_id).cloned();
+    }
+
+    pub fn club_playing_name(&self, data: &Data) -> String {
+        return match data.clubs.get(&self.club_playing_id) {
+            Some(c) => c.name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn _club_playing_short_name(&self, data: &Data) -> String {
+        return match data.clubs.get(&self.club_playing_id) {
+            Some(c) => c._short_name(),
+            None => String::new(),
+        };
+    }
+
+    pub fn preferences<'a>(&self, data: &'a Data) -> Option<&'a StaffPreferences> {
+        return data.staff_preferences.get(&self.preferences_id);
+    }
+
+    // Get the currency the person's money is shown in: the chosen one, or the one of their club's nation.
+    pub fn money_currency<'a>(&self, data: &'a Data, display_currency: Option<i32>) -> Option<&'a Currency> {
+        let nation_id = match self.club_contracted(data) {
+            Some(club) => club.nation_id,
+            None => self.nation_id,
+        };
+
+        return money::display_currency(data, display_currency, nation_id);
+    }
+
+    // Get the amount of a money column in the game's base currency. None if the column is not money or is empty.
+    pub fn money_amount(&self, data: &Data, column: &str) -> Option<i32> {
+        return match column {
+            "Estimated Wage" => Some(self.estimated_wage),
+            "Weekly Wage" => Some(self.estimated_wage_weekly),
+            "Estimated Value" => Some(self.estimated_value),
+            "Club Cash" => data.clubs.get(&self.club_contracted_id).map(|club| club.cash),
+            "Club Budget" => data.clubs.get(&self.club_contracted_id).map(|club| club.player_budget),
+            _ => None,
+        };
+    }
+
+    pub fn estimated_wage_text(&self, data: &Data, display_currency: Option<i32>) -> String {
+        return money::format_money(self.estimated_wage, self.money_currency(data, display_currency));
+    }
+
+    pub fn estimated_wage_weekly_text(&self, data: &Data, display_currency: Option<i32>) -> String {
+        return money::format_money(self.estimated_wage_weekly, self.money_currency(data, display_currency));
+    }
+
+    pub fn estimated_value_text(&self, data: &Data, display_currency: Option<i32>) -> String {
+        return money::format_money(self.estimated_value, self.money_currency(data, display_currency));
+    }
+
+    pub fn club_cash_text(&self, data: &Data, display_currency: Option<i32>) -> String {
+        return match self.club_contracted(data) {
+            Some(club) => money::format_money(club.cash, self.money_currency(data, display_currency)),
+            None => String::new(),
+        };
+    }
+
+    pub fn club_player_budget_text(&self, data: &Data, display_currency: Option<i32>) -> String {
+        return match self.club_contracted(data) {
+            Some(club) => money::format_money(club.player_budget, self.money_currency(data, display_currency)),
+            None => String::new(),
+        };
+    }
+
+    pub fn player_data(&self, data: &Data) -> Option<Player> {
+        return data.players.get(&self.player_data_id).cloned();
+    }
+
+    // Get the index of the person's player rights record, if they have one.
+    // NOTE: The rights records are in a save file part that has not been decoded yet, so the holder is unknown.
+    pub fn player_rights_index(&self) -> Option<i32> {
+        if self.player_rights_index == -1 {
+            return None;
+        }
+
+        return Some(self.player_rights_index);
+    }
+
+    pub fn full_name(&self, data: &Data) -> String {
+        format!("{} {}", self.forename(data), self.surname(data))
+    }
+
+    // Get the nation the player has declared for, if one exists.
+    fn declared_nation_id(&self) -> Option<i32> {
+        if self.declared_nation == 0 {
+            return None;
+        }
+
+        if self.declared_nation == 1 {
+            return Some(self.nation_id);
+        }
+
+        return Some(self.second_nation_id);
+    }
+
+    pub fn check_player_filters(&self, data: &Data, f: &PlayerFilters) -> bool {
+        return self.has_nationality(&f.include_nationalities, &f.exclude_nationalities)
+        && self.check_national_team(&f.include_nationalities, f.national_team_check)
+        && self.has_second_nationality(f.second_nationality_check)
+        && self.has_declared(f.declared_check)
+        && self.has_given_age(f.birth_years)
+        && self.check_club_contracted(&f.include_clubs_contracted, &f.exclude_clubs_contracted)
+        && self.check_club_playing(&f.include_clubs_playing, &f.exclude_clubs_playing)
+        && self.check_comp_contracted(data, &f.include_comps_contracted, &f.exclude_comps_contracted)
+        && self.check_comp_playing(data, &f.include_comps_playing, &f.exclude_comps_playing)
+        && self.check_nation_contracted(data, &f.include_nations_contracted, &f.exclude_nations_contracted)
+        && self.check_nation_playing(data, &f.include_nations_playing, &f.exclude_nations_playing)
+    }
+
+    // Check if the person's club contracted matches the filters.
+    fn check_nation_contracted(&self, data: &Data, include: &[i32], exclude: &[i32]) -> bool {
+        if include.is_empty() && exclude.is_empty() { return true; }
+        let nation_id = match self.club_contracted(data) {
+            Some(club) => club.nation_id,
+            None => -1
+        };
+
+        if exclude.contains(&nation_id) {
+            return false;
+        }
+
+        return include.is_empty() || include.contains(&nation_id);
+    }
+
+    // Check if the person's club contracted matches the filters.
+    fn check_nation_playing(&self, data: &Data, include: &[i32], exclude: &[i32]) -> bool {
+        if include.is_empty() && exclude.is_empty() { return true; }
+        let nation_id = match self.club_playing(data) {
+            Some(club) => club.nation_id,
+            None => -1
+        };
+
+        if exclude.contains(&nation_id) {
+            return false;
+        }
+
+        return include.is_empty() || include.contains(&nation_id);
+    }
+
+    // Check if the person's club contracted matches the filters.
+    fn check_comp_contracted(&self, data: &Data, include: &[i32], exclude: &[i32]) -> bool {
+        if include.is_empty() && exclude.is_empty() { return true; }
+        let (division_id, reserve_division_id) = match self.club_contracted(data) {
+            Some(club) => (club.division_id, club.reserve_division_id),
+            None => (-1, -1)
+        };
+
+        if exclude.contains(&division_id)
+        || exclude.contains(&reserve_division_id) {
+            return false;
+        }
+
+        return include.is_empty()
+        || include.contains(&division_id)
+        || include.contains(&reserve_division_id);
+    }
+
+    // Check if the person's club playing matches the filters.
+    fn check_comp_playing(&self, data: &Data, include: &[i32], exclude: &[i32]) -> bool {
+        if include.is_empty() && exclude.is_empty() { return true; }
+        let (division_id, reserve_division_id) = match self.club_playing(data) {
+            Some(club) => (club.division_id, club.reserve_division_id),
+            None => (-1, -1)
+        };
+
+        if exclude.contains(&division_id)
+        || exclude.contains(&reserve_division_id) {
+            return false;
+        }
+
+        return include.is_empty()
+        || include.contains(&division_id)
+        || include.contains(&reserve_division_id);
+    }
+
+    // Check if the person's club contracted matches the filters.
+    fn check_club_contracted(&self, include: &[i32], exclude: &[i32]) -> bool {
+        if exclude.contains(&self.club_contracted_id) {
+            return false;
+        }
+
+        return include.is_empty()
+        || include.contains(&self.club_contracted_id);
+    }
+
+    // Check if the person's club playing matches the filters.
+    fn check_club_playing(&self, include: &[i32], exclude: &[i32]) -> bool {
+        if exclude.contains(&self.club_playing_id) {
+            return false;
+        }
+
+        return include.is_empty()
+        || include.contains(&self.club_playing_id);
+    }
+
+    // Check if the player falls within specified age group.
+    fn has_given_age(&self, birth_years: [i16; 2]) -> bool {
+        return self.date_of_birth.year >= birth_years[0]
+        && self.date_of_birth.year <= birth_years[1];
+    }
+
+    // Check if the player can play for one of the national teams of the given nations.
+    fn check_national_team(&self, include_nationalities: &[i32], national_team_check: Option<bool>) -> bool {
+        // None means either works.
+        if national_team_check.is_none() { return true; }
+
+        let national_team_check = national_team_check.unwrap();
+        let declared = self.declared_nation_id();
+        return (declared.is_none() || include_nationalities.contains(&declared.unwrap())) == national_team_check;
+    }
+
+    // Check if the person has the given nationality.
+    fn has_nationality(&self, include_nationalities: &[i32], exclude_nationalities: &[i32]) -> bool {
+        if exclude_nationalities.contains(&self.nation_id)
+        || exclude_nationalities.contains(&self.second_nation_id) {
+            return false;
+        }
+
+        if include_nationalities.is_empty()
+        || include_nationalities.contains(&self.nation_id) {
+            return true;
+        }
+
+        if self.second_nation_id != -1 && include_nationalities.contains(&self.second_nation_id)  {
+            return true;
+        }
+
+        return false;
+    }
+
+    // See if the person's second nationality matches filters.
+    fn has_second_nationality(&self, second_nationality_check: Option<bool>) -> bool {
+        if second_nationality_check.is_none() { return true; }
+        let second_nationality_check = second_nationality_check.unwrap();
+
+        return (self.second_nation_id == -1) != second_nationality_check;
+    }
+
+    // See if the person's national team declaration matches filters.
+    fn has_declared(&self, declared_check: Option<bool>) -> bool {
+        if declared_check.is_none() { return true; }
+        let declared_check = declared_check.unwrap();
+
+        return (self.declared_nation == 0) != declared_check;
+    }
+
+    // Check if the person's name has no special characters.
+    pub fn _has_no_special_characters(&self, data: &Data) -> bool {
+        let re = Regex::new(r"^[\w ]+$").unwrap();
+        re.is_match(format!("{} {}", self.forename(data), self.surname(data)).as_str())
+    }
+
+    // Create an array of player data.
+    pub fn create_player_view(
+        &self,
+        p: Player,
+        data: &Data,
+        headers: &[String],
+        counter: usize,
+        display_currency: Option<i32>,
+        scripts: &Scripts,
+    ) -> views::player::Player {
+        let mut player = views::player::Player {
+            id: self.id,
+            forename: self.forename(data),
+            surname: self.surname(data),
+            date_of_birth: self.date_of_birth.to_year_month_day(),
+            positions: p.position_vec(),
+
+            ..Default::default()
+        };
+
+        // The variables of the custom columns, made when the first one is needed.
+        let mut scope = None;
+        for header in headers {
+            let header = header.as_str();
+            match scripts.contains(header) {
+                true => {
+                    let scope = scope.get_or_insert_with(|| player_scope(data, self, &p));
+                    player.columns.push(scripts.column_value(header, scope));
+                }
+                false => player.add_column(data, counter, header, self, &p, display_currency),
+            }
+        }
+
+        return player;
+    }
+
+    // Get everything known about the player.
+    pub fn create_player_detail(
+        &self,
+        p: Player,
+        data: &Data,
+        display_currency: Option<i32>,
+    ) -> views::player_detail::PlayerDetail {
+        return views::player_detail::PlayerDetail {
+            id: self.id,
+            forename: self.forename(data),
+            surname: self.surname(data),
+            date_of_birth: self.date_of_birth.to_year_month_day(),
+            age: self.age,
+            birthplace: self.birthplace_detail(data),
+            nation: nation_name_with_id(data, self.nation_id),
+            second_nation: nation_name_with_id(data, self.second_nation_id),
+            declared_nation: match self.declared_nation_id() {
+                Some(id) => nation_name_with_id(data, id),
+                None => None,
+            },
+            club_contracted: club_name_with_id(data, self.club_contracted_id),
+            club_playing: club_name_with_id(data, self.club_playing_id),
+            contract: views::player_detail::Contract {
+                date_joined_club: self.date_joined_club.to_optional_year_month_day(),
+                contract_expires_club: self.contract_expires_club.to_optional_year_month_day(),
+                date_joined_nation: self.date_joined_nation.to_optional_year_month_day(),
+                contract_expires_nation: self.contract_expires_nation.to_optional_year_month_day(),
+                first_pro_contract: self.first_pro_contract.to_optional_year_month_day(),
+            },
+            preferences: self.preferences_detail(data),
+            money: views::player_detail::Money {
+                currency: self.money_currency(data, display_currency).map(|c| (c.id, c.name())),
+                estimated_wage: self.estimated_wage_text(data, display_currency),
+                estimated_wage_weekly: self.estimated_wage_weekly_text(data, display_currency),
+                estimated_value: self.estimated_value_text(data, display_currency),
+                club_cash: self.club_cash_text(data, display_currency),
+                club_player_budget: self.club_player_budget_text(data, display_currency),
+            },
+
+            estimated_wage: self.estimated_wage,
+            estimated_wage_weekly: self.estimated_wage_weekly,
+            estimated_value: self.estimated_value,
+            international_apps: self.international_apps,
+            international_goals: self.international_goals,
+            international_assists: self.international_assists,
+            stanley_cups_won: self.stanley_cups_won,
+
+            current_ability: p.current_ability,
+            potential_ability: p.potential_ability,
+            home_reputation: p.home_reputation,
+            current_reputation: p.current_reputation,
+            world_reputation: p.world_reputation,
+            squad_number: p.squad_number,
+            international_squad_number: p.international_squad_number,
+            favourite_number: p.favourite_number,
+            handedness: p.handedness,
+            height: p.height,
+            weight: p.weight,
+            morale: p.morale,
+            goalie_style: p.goalie_style,
+            junior_preference: p.junior_preference,
+            position: p.position_string(),
+            positions: p.position_vec(),
+
+            attributes: views::player_detail::ATTRIBUTES.iter()
+                .map(|attr_name| (attr_name.to_string(), self.attribute(&p, attr_name)))
+                .collect(),
+            ratings: self.position_ratings(&p, data),
+        };
+    }
+
+    // Get the birthplace with the city, state and nation separately.
+    fn birthplace_detail(&self, data: &Data) -> Option<views::player_detail::Birthplace> {
+        let town = self.birth_town(data)?;
+
+        return Some(views::player_detail::Birthplace {
+            city: (town.id, town._name()),
+            state: town.state(data).map(|s| (s.id, s.name())),
+            nation: town.nation(data).map(|n| (n.id, n.name())),
+        });
+    }
+
+    // Get the clubs and people the person likes and dislikes.
+    fn preferences_detail(&self, data: &Data) -> Option<views::player_detail::Preferences> {
+        let preferences = self.preferences(data)?;
+
+        let clubs = |ids: Vec<i32>| -> Vec<views::NamedId> {
+            ids.into_iter().filter_map(|id| club_name_with_id(data, id)).collect()
+        };
+        let staff = |ids: Vec<i32>| -> Vec<views::NamedId> {
+            ids.into_iter()
+                .filter_map(|id| data.staff.get(&id).map(|s| (id, s.full_name(data))))
+                .collect()
+        };
+
+        return Some(views::player_detail::Preferences {
+            favourite_clubs: clubs(preferences.favourite_club_ids()),
+            disliked_clubs: clubs(preferences.disliked_club_ids()),
+            favourite_staff: staff(preferences.favourite_staff_ids()),
+            disliked_staff: staff(preferences.disliked_staff_ids()),
+        });
+    }
+
+    // Get the ratings of the player for each position, with the attribute tiers behind them.
+    fn position_ratings(&self, p: &Player, data: &Data) -> Vec<views::player_detail::PositionRating> {
+        let positions = match p.is_goalie() {
+            true => Vec::from([
+                ("GK", self.gk_rating(data), &GK_SCORE_TIERS),
+            ]),
+            false => Vec::from([
+                ("LD", self.ld_rating(data), &D_SCORE_TIERS),
+                ("RD", self.rd_rating(data), &D_SCORE_TIERS),
+                ("LW", self.lw_rating(data), &W_SCORE_TIERS),
+                ("C", self.c_rating(data), &C_SCORE_TIERS),
+                ("RW", self.rw_rating(data), &W_SCORE_TIERS),
+            ]),
+        };
+
+        return positions.into_iter()
+            .map(|(position, rating, tiers)| views::player_detail::PositionRating {
+                position: position.to_string(),
+                rating,
+                score: self.attribute_score(p, tiers),
+                tiers: self.attribute_tiers(p, tiers).into_iter()
+                    .map(|(weight, attributes)| views::player_detail::RatingTier {
+                        weight,
+                        attributes: attributes.into_iter()
+                            .map(|(attr_name, value)| (attr_name.to_string(), value))
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+    }
+
+    // Get the dates when the person has the current age.
+    pub fn dates_with_this_age(&self) -> (SIDate, SIDate) {
+        let min = SIDate::new(self.date_of_birth.year + self.age, self.date_of_birth.day);
+        let max = SIDate::new(min.year + 1, min.day - 1);
+
+        return (min, max);
+    }
+
+    // Get the value of an attribute by its display name.
+    pub fn attribute(&self, p: &Player, attr_name: &str) -> Option<i8> {
+        return Some(match attr_name {
+            "Adaptability" => self.adaptability,
+            "Ambition" => self.ambition,
+            "Determination" => self.determination,
+            "Loyalty" => self.loyalty,
+            "Pressure" => self.pressure,
+            "Professionalism" => self.professionalism,
+            "Sportsmanship" => self.sportsmanship,
+            "Temperament" => self.temperament,
+            "Acceleration" => p.acceleration,
+            "Aggression" => p.aggression,
+            "Agility" => p.agility,
+            "Bravery" => p.bravery,
+            "Consistency" => p.consistency,
+            "Dirtiness" => p.dirtiness,
+            "Flair" => p.flair,
+            "Important Matches" => p.important_matches,
+            "Injury Proneness" => p.injury_proneness,
+            "Influence" => p.leadership,
+            "Natural Fitness" => p.natural_fitness,
+            "Speed" => p.pace,
+            "Stamina" => p.stamina,
+            "Strength" => p.strength,
+            "Teamwork" => p.teamwork,
+            "Versatility" => p.versatility,
+            "Work Rate" => p.work_rate,
+            "GK" => p.goaltender,
+            "LD" => p.left_defence,
+            "RD" => p.right_defence,
+            "LW" => p.left_wing,
+            "C" => p.center,
+            "RW" => p.right_wing,
+            "Agitation" => p.agitation,
+            "Defensive Role" => p.defensive_role,
+            "Offensive Role" => p.offensive_role,
+            "Pass Tendency" => p.pass_tendency,
+            attr_name => return p.convert_attribute(attr_name),
+        });
+    }
+
+    // Get the attributes of a position score by tier, along with the tier weights.
+    pub fn attribute_tiers(&self, p: &Player, tiers: &ScoreTiers) -> Vec<AttributeTier> {
+        return tiers.iter()
+            .map(|(weight, attr_names)| {
+                let attributes = attr_names.iter()
+                    .map(|attr_name| (*attr_name, self.attribute(p, attr_name)))
+                    .collect();
+
+                (*weight, attributes)
+            })
+            .collect();
+    }
+
+    // Get the person's 'score' with the given attribute tiers. Attributes the chart can not convert count as nothing.
+    fn attribute_score(&self, p: &Player, tiers: &ScoreTiers) -> usize {
+        return self.attribute_tiers(p, tiers).iter()
+            .map(|(weight, attributes)| {
+                weight * attributes.iter().filter_map(|(_, value)| *value).map(|value| value as usize).sum::<usize>()
+            })
+            .sum();
+    }
+
+    // Get the person's 'score' as a goalkeeper.
+    pub fn gk_attribute_score(&self, p: &Player) -> usize {
+        return self.attribute_score(p, &GK_SCORE_TIERS);
+    }
+
+    // Get the person's 'score' as a defender.
+    pub fn d_attribute_score(&self, p: &Player) -> usize {
+        return self.attribute_score(p, &D_SCORE_TIERS);
+    }
+
+    // Get the person's 'score' as a centre forward.
+    pub fn c_attribute_score(&self, p: &Player) -> usize {
+        return self.attribute_score(p, &C_SCORE_TIERS);
+    }
+
+    // Get the person's 'score' as a winger.
+    pub fn w_attribute_score(&self, p: &Player) -> usize {
+        return self.attribute_score(p, &W_SCORE_TIERS);
+    }
+
+    // Get the person's ability as a goalkeeper.
+    pub fn gk_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if !p.is_goalie() {
+            return -1.0;
+        }
+
+        let score = self.gk_attribute_score(&p);
+        return p.rating(data, score, data.worst_gk, data.best_gk);
+    }
+
+    // Get the person's ability as a defender.
+    fn d_rating(&self, data: &Data, p: &Player) -> f64 {
+        let score = self.d_attribute_score(&p);
+        return p.rating(data, score, data.worst_d, data.best_d);
+    }
+
+    // Get the person's ability as a winger.
+    fn w_rating(&self, data: &Data, p: &Player) -> f64 {
+        let score = self.w_attribute_score(&p);
+        return p.rating(data, score, data.worst_w, data.best_w);
+    }
+
+    // Get the person's ability as a centre forward.
+    pub fn c_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if p.is_goalie() {
+            return -1.0;
+        }
+
+        let score = self.c_attribute_score(&p);
+        return p.rating(data, score,data.worst_c, data.best_c) * p.position_rating(p.center);
+    }
+
+    // Get the person's ability as a left defender.
+    pub fn ld_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if p.is_goalie() {
+            return -1.0;
+        }
+
+        return self.d_rating(data, &p) * p.position_rating(p.left_defence);
+    }
+
+    // Get the person's ability as a right defender.
+    pub fn rd_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if p.is_goalie() {
+            return -1.0;
+        }
+
+        return self.d_rating(data, &p) * p.position_rating(p.right_defence);
+    }
+
+    // Get the person's ability as a left winger.
+    pub fn lw_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if p.is_goalie() {
+            return -1.0;
+        }
+
+        return self.w_rating(data, &p) * p.position_rating(p.left_wing);
+    }
+
+    // Get the person's ability as a right winger.
+    pub fn rw_rating(&self, data: &Data) -> f64 {
+        let p = self.player_data(data).unwrap();
+        if p.is_goalie() {
+            return -1.0;
+        }
+
+        return self.w_rating(data, &p) * p.position_rating(p.right_wing);
+    }
+
+    pub fn merge_players(
+        &self,
+        db: &Data,
+        save: &Data,
+        key: &[String; 6],
+        save_staff: &mut HashMap<[String; 6], Staff>,
+    ) -> Option<db::Player> {
+        let db_player = self.player_data(&db).unwrap();
+
+        let save_person = save_staff.remove(key);
+        if save_person.is_none() {
+            return None;
+        }
+        let save_person = save_person.unwrap();
+        let save_player = save_person.player_data(save).unwrap();
+
+        return Some(db::Player {
+            forename: self.forename(db),
+            surname: self.surname(db),
+            age: save_person.age,
+            birthplace: save_person.birthplace(save),
+            nation: save_person._nation_three_letter_name(save),
+            second_nation: save_person._second_nation_three_letter_name(save),
+            club: save_person._club_playing_short_name(save),
+            current_ability: save_player.current_ability,
+            anticipation_save: save_player.anticipation_raw,
+            anticipation_db: db_player.anticipation_raw,
+            balance_save: save_player.balance_raw,
+            balance_db: db_player.balance_raw,
+            decisions_save: save_player.decisions_raw,
+            decisions_db: db_player.decisions_raw,
+            movement_save: save_player.movement_raw,
+            movement_db: db_player.movement_raw,
+            one_on_ones_save: save_player.one_on_ones_raw,
+            one_on_ones_db: db_player.one_on_ones_raw,
+            passing_save: save_player.passing_raw,
+            passing_db: db_player.passing_raw,
+            positioning_save: save_player.positioning_raw,
+            positioning_db: db_player.positioning_raw,
+            reflexes_save: save_player.reflexes_raw,
+            reflexes_db: db_player.reflexes_raw,
+            vision_save: save_player.vision_raw,
+            vision_db: db_player.vision_raw,
+            blocker_save: save_player.blocker_raw,
+            blocker_db: db_player.blocker_raw,
+            checking_save: save_player.checking_raw,
+            checking_db: db_player.checking_raw,
+            deflections_save: save_player.deflections_raw,
+            deflections_db: db_player.deflections_raw,
+            deking_save: save_player.deking_raw,
+            deking_db: db_player.deking_raw,
+            faceoffs_save: save_player.faceoffs_raw,
+            faceoffs_db: db_player.faceoffs_raw,
+            fighting_save: save_player.fighting_raw,
+            fighting_db: db_player.fighting_raw,
+            glove_save: save_player.glove_raw,
+            glove_db: db_player.glove_raw,
+            hitting_save: save_player.hitting_raw,
+            hitting_db: db_player.hitting_raw,
+            pokecheck_save: save_player.pokecheck_raw,
+            pokecheck_db: db_player.pokecheck_raw,
+            rebounds_save: save_player.rebounds_raw,
+            rebounds_db: db_player.rebounds_raw,
+            recovery_save: save_player.recovery_raw,
+            recovery_db: db_player.recovery_raw,
+            slapshot_save: save_player.slapshot_raw,
+            slapshot_db: db_player.slapshot_raw,
+            stickhandling_save: save_player.stickhandling_raw,
+            stickhandling_db: db_player.stickhandling_raw,
+            wristshot_save: save_player.wristshot_raw,
+            wristshot_db: db_player.wristshot_raw,
+            gk: db_player.goaltender,
+            ld: db_player.left_defence,
+            rd: db_player.right_defence,
+            lw: db_player.left_wing,
+            c: db_player.center,
+            rw: db_player.right_wing,
+        });
+    }
+}
+
+// Get a nation's ID and name, if the nation exists.
+fn nation_name_with_id(data: &Data, id: i32) -> Option<views::NamedId> {
+    return data.nations.get(&id).map(|n| (id, n.name()));
+}
+
+// Get a club's ID and name, if the club exists.
+fn club_name_with_id(data: &Data, id: i32) -> Option<views::NamedId> {
+    return data.clubs.get(&id).map(|c| (id, c.name()));
+}
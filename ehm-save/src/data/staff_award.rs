@@ -0,0 +1,56 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, SHORT_TEXT_LENGTH, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct StaffAward {
+        id: i32,
+        continent_id: i32,
+        nation_id: i32,
+        comp_id: i32,
+        foreground_colour_id: i32,
+        background_colour_id: i32,
+        trim_colour_id: i32,
+        _reputation: i16,
+        _b_short_name: [u8; SHORT_TEXT_LENGTH as usize],
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        _b_name: Vec<u8>,
+        _gender_name_short: i8,
+        _gender_name: i8,
+    }
+}
+
+impl StaffAward {
+    fn _name(&self) -> String {
+        return bytes_to_string(&self._b_name);
+    }
+
+    fn _short_name(&self) -> String {
+        return bytes_to_string(&self._b_short_name);
+    }
+
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Continent", Table::Continents, self.continent_id),
+            ("Nation", Table::Nations, self.nation_id),
+            ("Competition", Table::Competitions, self.comp_id),
+            ("Foreground Colour", Table::Colours, self.foreground_colour_id),
+            ("Background Colour", Table::Colours, self.background_colour_id),
+            ("Trim Colour", Table::Colours, self.trim_colour_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let award = Self::read(cursor)?;
+        data.order_staff_awards.push(award.id);
+        data.staff_awards.insert(award.id, award);
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{data::{Data, integrity::{Reference, Table}, set_ids}, record::record};
+
+record! {
+    #[derive(Clone)]
+    pub struct StaffPreferences {
+        id: i32,
+        favourite_club1_id: i32,
+        favourite_club2_id: i32,
+        favourite_club3_id: i32,
+        disliked_club1_id: i32,
+        disliked_club2_id: i32,
+        disliked_club3_id: i32,
+        favourite_staff1_id: i32,
+        favourite_staff2_id: i32,
+        favourite_staff3_id: i32,
+        disliked_staff1_id: i32,
+        disliked_staff2_id: i32,
+        disliked_staff3_id: i32,
+    }
+}
+
+impl StaffPreferences {
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        let mut references = Vec::new();
+        references.extend(self.favourite_club_ids().into_iter().map(|id| ("Favourite Club", Table::Clubs, id)));
+        references.extend(self.disliked_club_ids().into_iter().map(|id| ("Disliked Club", Table::Clubs, id)));
+        references.extend(self.favourite_staff_ids().into_iter().map(|id| ("Favourite Staff", Table::Staff, id)));
+        references.extend(self.disliked_staff_ids().into_iter().map(|id| ("Disliked Staff", Table::Staff, id)));
+
+        return references;
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let preferences = Self::read(cursor)?;
+        data.order_staff_preferences.push(preferences.id);
+        data.staff_preferences.insert(preferences.id, preferences);
+
+        return Ok(());
+    }
+
+    // Get the IDs of the favourite clubs that are set.
+    pub fn favourite_club_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.favourite_club1_id, self.favourite_club2_id, self.favourite_club3_id]);
+    }
+
+    // Get the IDs of the disliked clubs that are set.
+    pub fn disliked_club_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.disliked_club1_id, self.disliked_club2_id, self.disliked_club3_id]);
+    }
+
+    // Get the IDs of the favourite staff that are set.
+    pub fn favourite_staff_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.favourite_staff1_id, self.favourite_staff2_id, self.favourite_staff3_id]);
+    }
+
+    // Get the IDs of the disliked staff that are set.
+    pub fn disliked_staff_ids(&self) -> Vec<i32> {
+        return set_ids(&[self.disliked_staff1_id, self.disliked_staff2_id, self.disliked_staff3_id]);
+    }
+}
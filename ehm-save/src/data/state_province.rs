@@ -0,0 +1,50 @@
+use std::io::Cursor;
+
+use binread::{BinRead, Error};
+
+use crate::{
+    chars::bytes_to_string, data::{Data, REAL_SHORT_TEXT_LENGTH, STANDARD_TEXT_LENGTH, integrity::{Reference, Table}}, record::record
+};
+
+record! {
+    #[derive(Clone)]
+    pub struct StateProvince {
+        pub id: i32,
+        nation_id: i32,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        _b_name: Vec<u8>,
+        #[br(count = STANDARD_TEXT_LENGTH)]
+        _b_short_name: Vec<u8>,
+        _gender_name: i8,
+        _b_abbreviation: [u8; REAL_SHORT_TEXT_LENGTH as usize],
+    }
+}
+
+impl StateProvince {
+    // Get the IDs this refers to in other tables.
+    pub fn references(&self) -> Vec<Reference> {
+        return Vec::from([
+            ("Nation", Table::Nations, self.nation_id),
+        ]);
+    }
+
+    pub fn parse(data: &mut Data, cursor: &mut Cursor<&[u8]>) -> Result<(), Error> {
+        let province = Self::read(cursor)?;
+        data.order_states_provinces.push(province.id);
+        data.states_provinces.insert(province.id, province);
+
+        return Ok(());
+    }
+
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self._b_name);
+    }
+
+    fn _short_name(&self) -> String {
+        return bytes_to_string(&self._b_short_name);
+    }
+
+    pub fn _abbreviation(&self) -> String {
+        return bytes_to_string(&self._b_abbreviation);
+    }
+}
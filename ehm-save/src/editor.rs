@@ -0,0 +1,117 @@
+// Renaming people, clubs, competitions and nations.
+
+use std::fmt;
+
+use crate::{
+    chars::{EncodeError, bytes_to_string, string_to_si_bytes},
+    data::{Data, staff::Staff},
+    views::text_field::TextField,
+};
+
+#[derive(Debug)]
+pub enum EditError {
+    NotFound(String, i32),
+    UnknownField(String),
+    Text(String, EncodeError),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            EditError::NotFound(kind, id) => write!(f, "There is no {kind} with the ID {id}"),
+            EditError::UnknownField(field) => write!(f, "'{field}' is not a text field"),
+            EditError::Text(field, e) => write!(f, "{field}: {e}"),
+        };
+    }
+}
+
+// Get the text fields of an entity.
+// The kind is one of "club", "nat_club", "competition", "nat_competition" or "nation".
+pub fn text_fields(data: &Data, kind: &str, id: i32) -> Result<Vec<TextField>, EditError> {
+    let not_found = || EditError::NotFound(kind.to_string(), id);
+
+    let fields = match kind {
+        "club" => data.clubs.get(&id).ok_or_else(not_found)?.text_fields(),
+        "nat_club" => data.nat_clubs.get(&id).ok_or_else(not_found)?.text_fields(),
+        "competition" => data.competitions.get(&id).ok_or_else(not_found)?.text_fields(),
+        "nat_competition" => data.nat_competitions.get(&id).ok_or_else(not_found)?.text_fields(),
+        "nation" => data.nations.get(&id).ok_or_else(not_found)?.text_fields(),
+        _ => return Err(not_found()),
+    };
+
+    return Ok(fields.into_iter()
+        .map(|(field, bytes)| TextField {
+            field: field.to_string(),
+            text: bytes_to_string(bytes),
+            max_length: bytes.len() - 1,
+        })
+        .collect());
+}
+
+// Set a text field of an entity. Nothing is changed if the text does not fit the field.
+pub fn set_text(data: &mut Data, kind: &str, id: i32, field: &str, text: &str) -> Result<(), EditError> {
+    let not_found = || EditError::NotFound(kind.to_string(), id);
+
+    let bytes = match kind {
+        "club" => data.clubs.get_mut(&id).ok_or_else(not_found)?.text_field_mut(field),
+        "nat_club" => data.nat_clubs.get_mut(&id).ok_or_else(not_found)?.text_field_mut(field),
+        "competition" => data.competitions.get_mut(&id).ok_or_else(not_found)?.text_field_mut(field),
+        "nat_competition" => data.nat_competitions.get_mut(&id).ok_or_else(not_found)?.text_field_mut(field),
+        "nation" => data.nations.get_mut(&id).ok_or_else(not_found)?.text_field_mut(field),
+        _ => return Err(not_found()),
+    };
+
+    let bytes = bytes.ok_or_else(|| EditError::UnknownField(field.to_string()))?;
+    let encoded = string_to_si_bytes(text, bytes.len() as u8)
+        .map_err(|e| EditError::Text(field.to_string(), e))?;
+
+    bytes.copy_from_slice(&encoded);
+    return Ok(());
+}
+
+// Rename a person.
+pub fn rename_staff(data: &mut Data, staff_id: i32, forename: &str, surname: &str) -> Result<(), EditError> {
+    if !data.staff.contains_key(&staff_id) {
+        return Err(EditError::NotFound("staff".to_string(), staff_id));
+    }
+
+    return Staff::rename(data, staff_id, forename, surname)
+        .map_err(|e| EditError::Text("Name".to_string(), e));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicBool;
+
+    use crate::init::{tests::large_save, try_load_save};
+
+    use super::*;
+
+    fn load(save: Vec<u8>) -> Data {
+        return try_load_save(save, &AtomicBool::new(false), |_| {}).unwrap();
+    }
+
+    #[test]
+    fn renamed_people_are_saved() {
+        let mut data = load(large_save(2));
+        rename_staff(&mut data, 1, "Petr", "Černý").unwrap();
+
+        let data = load(data.save_file().unwrap());
+        assert_eq!(data.staff[&0].full_name(&data), "Name Name");
+        assert_eq!(data.staff[&1].full_name(&data), "Petr Černý");
+    }
+
+    #[test]
+    fn text_that_can_not_be_saved_is_rejected() {
+        let mut data = load(large_save(1));
+
+        let result = rename_staff(&mut data, 0, "Łukasz", "Name");
+        assert!(matches!(result, Err(EditError::Text(_, EncodeError::Unrepresentable('Ł')))));
+
+        let result = rename_staff(&mut data, 0, "Name", &"a".repeat(51));
+        assert!(matches!(result, Err(EditError::Text(_, EncodeError::TooLong { length: 51, max: 50 }))));
+
+        assert!(matches!(rename_staff(&mut data, 5, "Name", "Name"), Err(EditError::NotFound(_, 5))));
+        assert_eq!(data.staff[&0].full_name(&data), "Name Name");
+    }
+}
@@ -0,0 +1,28 @@
+// The player filters set in the frontend.
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlayerFilters {
+    pub birth_years: [i16; 2],
+    pub national_team_check: Option<bool>,
+    pub second_nationality_check: Option<bool>,
+    pub declared_check: Option<bool>,
+    pub include_nationalities: Vec<i32>,
+    pub exclude_nationalities: Vec<i32>,
+    pub include_clubs_contracted: Vec<i32>,
+    pub exclude_clubs_contracted: Vec<i32>,
+    pub include_clubs_playing: Vec<i32>,
+    pub exclude_clubs_playing: Vec<i32>,
+    pub include_comps_contracted: Vec<i32>,
+    pub exclude_comps_contracted: Vec<i32>,
+    pub include_comps_playing: Vec<i32>,
+    pub exclude_comps_playing: Vec<i32>,
+    pub include_nations_contracted: Vec<i32>,
+    pub exclude_nations_contracted: Vec<i32>,
+    pub include_nations_playing: Vec<i32>,
+    pub exclude_nations_playing: Vec<i32>,
+    // The names of the filter scripts the players have to pass.
+    pub scripts: Vec<String>,
+}
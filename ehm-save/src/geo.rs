@@ -0,0 +1,106 @@
+// Maps of where the players come from and where they play.
+
+use std::collections::HashMap;
+
+use serde_json::{Value, json};
+
+use crate::data::{Data, city::City, player::Player, staff::Staff};
+
+// Get the birthplaces of the players and the arenas of their clubs as a GeoJSON feature collection.
+pub fn players_geojson(data: &Data, players: &[(&Staff, Player)]) -> Value {
+    let mut features = birthplace_features(data, players);
+    features.append(&mut arena_features(data, players));
+
+    return json!({
+        "type": "FeatureCollection",
+        "features": features,
+    });
+}
+
+// Get one feature per birth city, with the players born there.
+fn birthplace_features(data: &Data, players: &[(&Staff, Player)]) -> Vec<Value> {
+    let mut cities: HashMap<i32, (City, Vec<&Staff>)> = HashMap::new();
+    for (person, _) in players {
+        let town = match person.birth_town(data) {
+            Some(t) => t,
+            None => continue,
+        };
+
+        cities.entry(town.id).or_insert_with(|| (town, Vec::new())).1.push(*person);
+    }
+
+    let mut cities: Vec<(City, Vec<&Staff>)> = cities.into_values().collect();
+    cities.sort_by_key(|(city, _)| city.id);
+
+    return cities.into_iter()
+        .filter(|(city, _)| city.has_coordinates())
+        .map(|(city, people)| {
+            let players: Vec<Value> = people.iter()
+                .map(|p| json!({ "id": p.id, "name": p.full_name(data) }))
+                .collect();
+
+            point_feature(&city, json!({
+                "kind": "birthplace",
+                "cityId": city.id,
+                "city": city._name(),
+                "state": city.state(data).map(|s| s.name()),
+                "nation": city.nation(data).map(|n| n.name()),
+                "count": players.len(),
+                "players": players,
+            }))
+        })
+        .collect();
+}
+
+// Get one feature per arena used by the clubs the players are contracted to.
+fn arena_features(data: &Data, players: &[(&Staff, Player)]) -> Vec<Value> {
+    let mut arenas: HashMap<i32, Vec<i32>> = HashMap::new();
+    for (person, _) in players {
+        let club = match data.clubs.get(&person.club_contracted_id) {
+            Some(c) => c,
+            None => continue,
+        };
+
+        let club_ids = arenas.entry(club.stadium_id).or_default();
+        if !club_ids.contains(&person.club_contracted_id) {
+            club_ids.push(person.club_contracted_id);
+        }
+    }
+
+    let mut arena_ids: Vec<i32> = arenas.keys().copied().collect();
+    arena_ids.sort();
+
+    return arena_ids.into_iter()
+        .filter_map(|arena_id| {
+            let arena = data.arenas.get(&arena_id)?;
+            let city = data.cities.get(&arena.city_id)?;
+            if !city.has_coordinates() {
+                return None;
+            }
+
+            let clubs: Vec<String> = arenas.get(&arena_id).unwrap().iter()
+                .map(|id| data.clubs.get(id).unwrap().name())
+                .collect();
+
+            return Some(point_feature(city, json!({
+                "kind": "arena",
+                "arenaId": arena.id,
+                "arena": arena.name(),
+                "city": city._name(),
+                "capacity": arena.capacity,
+                "clubs": clubs,
+            })));
+        })
+        .collect();
+}
+
+fn point_feature(city: &City, properties: Value) -> Value {
+    return json!({
+        "type": "Feature",
+        "geometry": {
+            "type": "Point",
+            "coordinates": [city.longitude, city.latitude],
+        },
+        "properties": properties,
+    });
+}
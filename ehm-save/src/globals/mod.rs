@@ -6,7 +6,7 @@ use lazy_static::lazy_static;
 
 use crate::data::{Data, arena::Arena, city::City, club::Club, colour::Colour, competition::Competition, competition_history::CompetitionHistory, continent::Continent, currency::Currency, draft::Draft, injury::Injury, name::Name, nation::Nation, non_player::NonPlayer, official::Official, player::Player, retired_number::RetiredNumber, staff::Staff, staff_award::StaffAward, staff_preferences::StaffPreferences, stage_name::StageName, state_province::StateProvince};
 
-pub type ParseFunc = fn(&mut Data, &mut Cursor<Vec<u8>>) -> Result<(), Error>;
+pub type ParseFunc = fn(&mut Data, &mut Cursor<&[u8]>) -> Result<(), Error>;
 
 lazy_static! {
     pub static ref PARSER_GUIDE: HashMap<String, ParseFunc> = {
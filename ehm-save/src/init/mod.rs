@@ -0,0 +1,421 @@
+/* Load stuff. */
+pub mod debug;
+
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, Cursor, Read as _, Write},
+    path::{Path, PathBuf},
+    ops::Range,
+    sync::{Arc, atomic::{AtomicBool, Ordering}, mpsc},
+    thread,
+};
+
+use binread::{BinRead, BinResult, Error};
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    chars::bytes_to_string, data::
+        Data, globals::{PARSER_GUIDE, ParseFunc}, init::debug::_check_players, record::record
+};
+
+record! {
+    #[derive(Clone, Debug)]
+    pub struct Header {
+        _compressed: i32,
+        _header: i32,
+        files: i32,
+    }
+}
+
+record! {
+    #[derive(Debug, Clone, Default)]
+    pub struct FileIndex {
+        pub start_position: u32,
+        pub size: u32,
+
+        #[br(count = 260)]
+        pub b_name: Vec<u8>,
+    }
+}
+
+impl FileIndex {
+    // Get the string of the name.
+    pub fn name(&self) -> String {
+        return bytes_to_string(&self.b_name);
+    }
+
+    // Get where the part is in the whole save file.
+    fn range(&self) -> Range<usize> {
+        let start = self.start_position as usize;
+        return start..start + self.size as usize;
+    }
+
+    // Get the binary of the part from the whole save file.
+    fn bin<'a>(&self, save: &'a [u8]) -> Result<&'a [u8], Error> {
+        return save.get(self.range()).ok_or(Error::Io(io::ErrorKind::UnexpectedEof.into()));
+    }
+}
+
+// A part of the save file that is kept as-is. It points into the loaded save file instead of copying it.
+// NOTE: The whole save file stays in memory for as long as any of its parts does, including the bytes of the parsed
+// parts. Loading copies nothing in exchange; copying the parts out instead would free the parsed bytes.
+#[derive(Clone, Debug)]
+pub struct Binary {
+    save: Arc<Vec<u8>>,
+    range: Range<usize>,
+}
+
+impl Binary {
+    pub fn bytes(&self) -> &[u8] {
+        return &self.save[self.range.clone()];
+    }
+}
+
+// An error from loading a save file or a database folder.
+#[derive(Debug)]
+pub enum LoadError {
+    Io(PathBuf, io::Error),
+    Leftovers(String, u64),
+    // A part of the save file could not be read.
+    Read(String, Error),
+    Cancelled,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            LoadError::Io(path, e) => write!(f, "{} - {e}", path.display()),
+            LoadError::Leftovers(name, leftovers) => write!(f, "{name} has {leftovers} leftover bytes"),
+            LoadError::Read(name, e) => write!(f, "{e} - file name: {name}"),
+            LoadError::Cancelled => write!(f, "Loading was cancelled"),
+        };
+    }
+}
+
+// How far the loading of a save file is.
+#[derive(Serialize, Clone, Debug)]
+pub struct Progress {
+    // The name of the part that was parsed last.
+    pub part: String,
+    pub parsed: usize,
+    pub parts: usize,
+}
+
+pub fn _load_debug_bin(path: &Path) -> Data {
+    let save = load_bin(path);
+
+    let mut debug_save = save.clone();
+    _check_players(&mut debug_save);
+
+    let debug_bin = debug_save.save_file().unwrap();
+
+    let debug_path = Path::new("C:/Users/Aleksi/Documents/Sports Interactive/EHM 2007/games/test_debug.sav");
+    let mut file = File::create(debug_path).unwrap();
+    file.write_all(&debug_bin).unwrap();
+
+    return load_bin(debug_path);
+}
+
+// Load the binary.
+pub fn load_bin(path: &Path) -> Data {
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => panic!("{e} - path: {}", path.to_str().unwrap()),
+    };
+
+    return load_save(file);
+}
+
+pub fn load_save(mut file: File) -> Data {
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).unwrap();
+
+    return match try_load_save(buffer, &AtomicBool::new(false), |_| {}) {
+        Ok(data) => data,
+        Err(e) => panic!("{e}"),
+    };
+}
+
+// Load the binary, report the progress after each part and stop when cancel is set.
+pub fn try_load_bin(path: &Path, cancel: &AtomicBool, progress: impl FnMut(&Progress)) -> Result<Data, LoadError> {
+    let buffer = fs::read(path).map_err(|e| LoadError::Io(path.to_path_buf(), e))?;
+    return try_load_save(buffer, cancel, progress);
+}
+
+// The parts are parsed straight from the buffer, which the parts without a parser keep pointing to.
+pub fn try_load_save(buffer: Vec<u8>, cancel: &AtomicBool, progress: impl FnMut(&Progress)) -> Result<Data, LoadError> {
+    let save = Arc::new(buffer);
+    let mut cursor = Cursor::new(save.as_slice());
+
+    let mut data = Data::initialise(&mut cursor).map_err(|e| LoadError::Read("header".to_string(), e))?;
+
+    parse_files(&save, &mut data, cancel, progress)?;
+
+    data.calculate_ingame_date();
+    data.calculate_rating_boundaries();
+
+    // data.create_character_csv();
+    return Ok(data);
+}
+
+// Load the .dat files of an EHM database folder.
+pub fn load_database_folder(path: &Path) -> Result<Data, LoadError> {
+    let mut data = Data::new();
+
+    // Parse the files in the same order every time.
+    let mut filenames: Vec<&String> = PARSER_GUIDE.keys().collect();
+    filenames.sort();
+
+    for filename in filenames {
+        let file_path = path.join(filename);
+        let buffer = fs::read(&file_path).map_err(|e| LoadError::Io(file_path, e))?;
+
+        let file_size = buffer.len() as u64;
+        let mut cursor = Cursor::new(buffer.as_slice());
+        try_parse_file(&mut cursor, PARSER_GUIDE.get(filename).unwrap(), &mut data, file_size, filename)?;
+    }
+
+    data.calculate_ingame_date();
+    data.calculate_rating_boundaries();
+
+    return Ok(data);
+}
+
+// Read file indexes.
+pub fn read_file_indexes(cursor: &mut Cursor<&[u8]>, header: &Header) -> BinResult<Vec<FileIndex>> {
+    let mut file_indexes = Vec::new();
+    for _ in 0..header.files {
+        let index = FileIndex::read(cursor)?;
+        file_indexes.push(index);
+    }
+
+    return Ok(file_indexes);
+}
+
+// A part of the save file after parsing.
+enum Part {
+    Parsed(Box<Data>),
+    // A part without a parser, kept as-is.
+    Binary(String, Binary),
+}
+
+// Parse the files on a thread pool, each into its own data, and merge them in the order of the file indexes.
+// Check for cancelling before each one and report the progress after it.
+pub fn parse_files(
+    save: &Arc<Vec<u8>>,
+    data: &mut Data,
+    cancel: &AtomicBool,
+    mut progress: impl FnMut(&Progress),
+) -> Result<(), LoadError> {
+    let file_indexes = data.file_indexes.clone();
+
+    // FileIndex::debug_csv(&file_indexes);
+
+    let (sender, receiver) = mpsc::channel();
+    let parts: Vec<Result<Part, LoadError>> = thread::scope(|scope| {
+        let parsing = scope.spawn(|| {
+            file_indexes.par_iter().map_with(sender, |sender, index| {
+                let part = parse_part(save, index, cancel);
+                if part.is_ok() {
+                    sender.send(index.name()).unwrap();
+                }
+
+                part
+            }).collect()
+        });
+
+        // The progress is reported here so that the callback does not need to be shared between threads.
+        for (i, name) in receiver.iter().enumerate() {
+            progress(&Progress { part: name, parsed: i + 1, parts: file_indexes.len() });
+        }
+
+        parsing.join().unwrap()
+    });
+
+    for part in parts {
+        match part? {
+            Part::Parsed(part) => data.merge(*part),
+            Part::Binary(name, binary) => {
+                data.binaries.insert(name, binary);
+            }
+        };
+    }
+
+    return Ok(());
+}
+
+fn parse_part(save: &Arc<Vec<u8>>, index: &FileIndex, cancel: &AtomicBool) -> Result<Part, LoadError> {
+    if cancel.load(Ordering::Relaxed) {
+        return Err(LoadError::Cancelled);
+    }
+
+    let name = index.name();
+    let bin = index.bin(save).map_err(|e| LoadError::Read(name.clone(), e))?;
+
+    return match PARSER_GUIDE.get(name.as_str()) {
+        Some(parser) => {
+            let mut part = Data::new();
+            try_parse_file(&mut Cursor::new(bin), parser, &mut part, index.size as u64, name.as_str())?;
+            Ok(Part::Parsed(Box::new(part)))
+        }
+        None => Ok(Part::Binary(name, Binary { save: Arc::clone(save), range: index.range() })),
+    };
+}
+
+pub fn parse_file(
+    cursor: &mut Cursor<&[u8]>,
+    parser: &ParseFunc,
+    data: &mut Data,
+    file_size: u64,
+    name: &str,
+) {
+    if let Err(e) = try_parse_file(cursor, parser, data, file_size, name) {
+        panic!("{e}");
+    }
+}
+
+// Parse the file, and return an error if it was not parsed to the end.
+pub fn try_parse_file(
+    cursor: &mut Cursor<&[u8]>,
+    parser: &ParseFunc,
+    data: &mut Data,
+    file_size: u64,
+    name: &str,
+) -> Result<(), LoadError> {
+    loop {
+        match parser(data, cursor) {
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    // Make sure the cursor has reached the end of the file.
+    let leftovers = file_size - cursor.position();
+    if leftovers != 0 {
+        return Err(LoadError::Leftovers(name.to_string(), leftovers));
+    }
+
+    return Ok(());
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::time::Instant;
+
+    use crate::{data::{SaveError, chart_check::ConversionProblem, player::Player, staff::Staff}, record::Record, views};
+
+    use super::*;
+
+    // Put the parts together into a save file.
+    fn build_save(parts: &[(&str, Vec<u8>)]) -> Vec<u8> {
+        let header = Header { _compressed: 0, _header: 0, files: parts.len() as i32 };
+        let mut bytes = header.to_bytes();
+
+        let mut start_position = 12 + 268 * parts.len() as u32;
+        for (name, part) in parts {
+            let mut b_name = name.as_bytes().to_vec();
+            b_name.resize(260, 0);
+
+            let index = FileIndex { start_position, size: part.len() as u32, b_name };
+            bytes.append(&mut index.to_bytes());
+            start_position += part.len() as u32;
+        }
+
+        for (_, part) in parts {
+            bytes.extend_from_slice(part);
+        }
+
+        return bytes;
+    }
+
+    // A save file with one part that has no parser.
+    fn save_file() -> Vec<u8> {
+        return build_save(&[("unknown.dat", vec![1, 2, 3, 4])]);
+    }
+
+    // A save file with a name and the count of people and players, each with their own player data.
+    pub(crate) fn large_save(count: i32) -> Vec<u8> {
+        return save_with_players(count, 1);
+    }
+
+    // A save file like `large_save` whose players have the current ability.
+    fn save_with_players(count: i32, current_ability: i16) -> Vec<u8> {
+        // The text, ID, nation ID and count of a name.
+        let mut name = b"Name".to_vec();
+        name.resize(51 + 4 + 4 + 1, 0);
+
+        let mut staff = Vec::new();
+        let mut players = Vec::new();
+        for id in 0..count {
+            let mut person = Staff::default().to_bytes();
+            // The ID and the player data ID.
+            person[0..4].copy_from_slice(&id.to_le_bytes());
+            person[20..24].copy_from_slice(&id.to_le_bytes());
+            staff.append(&mut person);
+
+            players.append(&mut Player { id, current_ability, ..Player::default() }.to_bytes());
+        }
+
+        return build_save(&[
+            ("first_names.dat", name.clone()),
+            ("second_names.dat", name),
+            ("staff.dat", staff),
+            ("player.dat", players),
+            ("unknown.dat", vec![0; 1_000_000]),
+        ]);
+    }
+
+    #[test]
+    fn progress_is_reported() {
+        let mut reported = Vec::new();
+        let data = try_load_save(save_file(), &AtomicBool::new(false), |p| reported.push(p.clone())).unwrap();
+
+        assert_eq!(data.binaries["unknown.dat"].bytes(), [1, 2, 3, 4]);
+        assert_eq!(reported.len(), 1);
+        assert_eq!((reported[0].part.as_str(), reported[0].parsed, reported[0].parts), ("unknown.dat", 1, 1));
+    }
+
+    #[test]
+    fn loading_can_be_cancelled() {
+        let result = try_load_save(save_file(), &AtomicBool::new(true), |_| panic!("Nothing should be parsed"));
+        assert!(matches!(result, Err(LoadError::Cancelled)));
+
+        let result = try_load_save(save_file()[..20].to_vec(), &AtomicBool::new(false), |_| {});
+        assert!(matches!(result, Err(LoadError::Read(..))));
+    }
+
+    #[test]
+    fn parts_are_saved_in_order() {
+        let save = large_save(1000);
+        let mut data = try_load_save(save.clone(), &AtomicBool::new(false), |_| {}).unwrap();
+
+        assert!(data.save_file().unwrap() == save);
+        assert!(matches!(Data::new().save_file(), Err(SaveError::NotASave)));
+    }
+
+    #[test]
+    fn unconvertible_attributes_are_reported() {
+        // The chart has no current ability 0.
+        let data = try_load_save(save_with_players(1, 0), &AtomicBool::new(false), |_| {}).unwrap();
+        assert_eq!(data.check_attributes(), [ConversionProblem::UnknownCa { player_id: 0, ca: 0 }]);
+
+        let detail = views::player_detail(&data, 0, None).unwrap();
+        let (_, anticipation) = detail.attributes.iter().find(|(name, _)| name == "Anticipation").unwrap();
+        assert_eq!(*anticipation, None);
+    }
+
+    // Run with `cargo test --release -p ehm-save load_time -- --ignored --nocapture`. The save is synthetic, so the time
+    // says nothing about how long a real save takes or whether parsing the parts in parallel makes it faster.
+    #[test]
+    #[ignore]
+    fn load_time() {
+        let save = large_save(200_000);
+        let start = Instant::now();
+        let data = try_load_save(save, &AtomicBool::new(false), |_| {}).unwrap();
+
+        println!("Loaded {} players in {:?}", data.players.len(), start.elapsed());
+        assert_eq!(data.players.len(), 200_000);
+    }
+}
@@ -0,0 +1,26 @@
+//! Reading, checking and writing NHL Eastside Hockey Manager 2007 saves and databases.
+//!
+//! - [`init::load_bin`] and [`init::load_database_folder`] load a save file or a database folder into [`data::Data`].
+//! - [`data`] has a type for every record, and [`data::Data::save_file`] and [`data::Data::save_database_folder`]
+//!   write the data back.
+//! - [`record`] declares the layout of every record once, for reading, writing and listing its fields.
+//! - [`data::try_convert_attribute`] converts attributes from the save file to in-game with [`globals::attr_chart`].
+//! - [`rating`] and [`money`] convert ratings and money to what the game shows.
+//! - [`views`] has the serialisable views of the data that the viewer shows.
+//! - [`scripts`] runs the user's Rhai scripts for custom columns and filters.
+
+pub mod chars;
+pub mod data;
+pub mod editor;
+pub mod filters;
+pub mod geo;
+pub mod globals;
+pub mod init;
+pub mod money;
+pub mod rating;
+pub mod record;
+pub mod relationships;
+pub mod research;
+pub mod scripts;
+pub mod transfer;
+pub mod views;
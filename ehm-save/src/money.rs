@@ -0,0 +1,92 @@
+// Showing money in the currencies of the save.
+// NOTE: Amounts are stored in the game's base currency, and exchange rates convert from it.
+
+use crate::data::{Data, currency::Currency};
+
+// Get the currency used in a nation, if it has one.
+pub fn nation_currency(data: &Data, nation_id: i32) -> Option<&Currency> {
+    return data.currencies.values().find(|c| c.nation_id == nation_id);
+}
+
+// Get the currency to show money in: the chosen display currency, or the given nation's currency.
+pub fn display_currency(data: &Data, display_currency_id: Option<i32>, nation_id: i32) -> Option<&Currency> {
+    return match display_currency_id {
+        Some(id) => data.currencies.get(&id),
+        None => nation_currency(data, nation_id),
+    };
+}
+
+// Format an amount in the base currency in the given currency, or as is if there is none.
+pub fn format_money(amount: i32, currency: Option<&Currency>) -> String {
+    let currency = match currency {
+        Some(c) => c,
+        None => return group_thousands(amount as f64),
+    };
+
+    let number = group_thousands(currency.convert(amount));
+    let symbol = currency.symbol();
+
+    // Single-character symbols such as $ and € go before the number.
+    if symbol.chars().count() == 1 {
+        return match number.strip_prefix('-') {
+            Some(n) => format!("-{symbol}{n}"),
+            None => format!("{symbol}{number}"),
+        };
+    }
+
+    if symbol.is_empty() {
+        return format!("{number} {}", currency.three_letter_code());
+    }
+
+    return format!("{number} {symbol}");
+}
+
+// Round the number and separate the thousands with commas.
+fn group_thousands(amount: f64) -> String {
+    let rounded = amount.round() as i64;
+    let digits = rounded.unsigned_abs().to_string();
+
+    let mut grouped = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i != 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    if rounded < 0 {
+        return format!("-{grouped}");
+    }
+
+    return grouped;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::record::test_record;
+
+    use super::*;
+
+    fn currency(exchange_rate: f64, code: &str, symbol: &str) -> Currency {
+        return test_record(&[
+            ("exchange_rate", &exchange_rate.to_le_bytes()),
+            ("b_three_letter_code", code.as_bytes()),
+            ("b_symbol", symbol.as_bytes()),
+        ]);
+    }
+
+    #[test]
+    fn converts_from_the_base_currency() {
+        assert_eq!(currency(7.5, "SEK", "kr").convert(1000), 7500.0);
+        assert_eq!(currency(0.5, "USD", "$").convert(1000), 500.0);
+    }
+
+    #[test]
+    fn formats_money() {
+        assert_eq!(format_money(1234567, None), "1,234,567");
+        assert_eq!(format_money(1000, Some(&currency(1.0, "USD", "$"))), "$1,000");
+        assert_eq!(format_money(-1000, Some(&currency(1.0, "USD", "$"))), "-$1,000");
+        assert_eq!(format_money(1000, Some(&currency(7.5, "SEK", "kr"))), "7,500 kr");
+        assert_eq!(format_money(100, Some(&currency(1.0, "CHF", ""))), "100 CHF");
+    }
+}
@@ -0,0 +1,262 @@
+// The layouts of the records in the save file. A layout is declared once with `record!`, which reads it with
+// `BinRead` and writes it with `Record::to_bytes` in the same order, and lists its fields for tooling.
+
+use serde::Serialize;
+
+// A field of a record and where it is in the record's bytes.
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub struct Field {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+    pub type_name: &'static str,
+}
+
+pub trait Record {
+    // Add the bytes of the record as they are in the save file.
+    fn write_bytes(&self, bytes: &mut Vec<u8>);
+
+    // Get the fields in the order they are in the save file. Every record of the type has the same layout.
+    fn fields() -> Vec<Field>;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.write_bytes(&mut bytes);
+        return bytes;
+    }
+}
+
+// A value that can be a field of a record.
+pub trait WriteBytes {
+    fn write_bytes(&self, bytes: &mut Vec<u8>);
+}
+
+// A type whose values always take the same number of bytes.
+pub trait FixedSize {
+    fn size() -> usize;
+}
+
+// A `Vec` field, whose length is the count in its attribute.
+pub trait Counted {
+    fn item_size() -> usize;
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl WriteBytes for $ty {
+                fn write_bytes(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+
+            impl FixedSize for $ty {
+                fn size() -> usize {
+                    return size_of::<$ty>();
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(i8, u8, i16, u16, i32, u32, f64);
+
+impl<T: WriteBytes> WriteBytes for [T] {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.iter().for_each(|value| value.write_bytes(bytes));
+    }
+}
+
+impl<T: WriteBytes, const N: usize> WriteBytes for [T; N] {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.as_slice().write_bytes(bytes);
+    }
+}
+
+impl<T: FixedSize, const N: usize> FixedSize for [T; N] {
+    fn size() -> usize {
+        return T::size() * N;
+    }
+}
+
+impl<T: WriteBytes> WriteBytes for Vec<T> {
+    fn write_bytes(&self, bytes: &mut Vec<u8>) {
+        self.as_slice().write_bytes(bytes);
+    }
+}
+
+impl<T: FixedSize> Counted for Vec<T> {
+    fn item_size() -> usize {
+        return T::size();
+    }
+}
+
+// The size of a field, from its type or from the count of a `Vec`.
+macro_rules! field_size {
+    ($ty:ty) => {
+        <$ty as $crate::record::FixedSize>::size()
+    };
+    ($ty:ty, $count:expr) => {
+        <$ty as $crate::record::Counted>::item_size() * ($count) as usize
+    };
+}
+
+// Declare a record. The struct is read little-endian with `BinRead`. A `Vec` field needs the count of its items,
+// e.g. `#[br(count = STANDARD_TEXT_LENGTH)]`.
+macro_rules! record {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[br(count = $count:expr)])?
+                $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(binread::BinRead)]
+        #[br(little)]
+        $vis struct $name {
+            $(
+                $(#[br(count = $count)])?
+                $field_vis $field: $ty,
+            )*
+        }
+
+        impl $crate::record::Record for $name {
+            fn write_bytes(&self, bytes: &mut Vec<u8>) {
+                $($crate::record::WriteBytes::write_bytes(&self.$field, bytes);)*
+            }
+
+            fn fields() -> Vec<$crate::record::Field> {
+                let mut fields = vec![$(
+                    $crate::record::Field {
+                        name: stringify!($field),
+                        offset: 0,
+                        size: $crate::record::field_size!($ty $(, $count)?),
+                        type_name: stringify!($ty),
+                    },
+                )*];
+
+                let mut offset = 0;
+                for field in fields.iter_mut() {
+                    field.offset = offset;
+                    offset += field.size;
+                }
+
+                return fields;
+            }
+        }
+
+        impl $crate::record::WriteBytes for $name {
+            fn write_bytes(&self, bytes: &mut Vec<u8>) {
+                $crate::record::Record::write_bytes(self, bytes);
+            }
+        }
+
+        impl $crate::record::FixedSize for $name {
+            fn size() -> usize {
+                return <$name as $crate::record::Record>::fields().iter().map(|field| field.size).sum();
+            }
+        }
+    };
+}
+
+pub(crate) use {field_size, record};
+
+// Read a record from bytes that are zero except for the values of the named fields.
+#[cfg(test)]
+pub(crate) fn test_record<T: binread::BinRead<Args = ()> + Record>(values: &[(&str, &[u8])]) -> T {
+    let fields = T::fields();
+    let mut bytes = vec![0; fields.iter().map(|field| field.size).sum()];
+
+    for (name, value) in values {
+        let field = fields.iter().find(|field| field.name == *name).unwrap();
+        assert!(value.len() <= field.size, "{name} is {} bytes", field.size);
+        bytes[field.offset..field.offset + value.len()].copy_from_slice(value);
+    }
+
+    return T::read(&mut std::io::Cursor::new(bytes.as_slice())).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use binread::BinRead;
+
+    use crate::{
+        data::{
+            SIDate, arena::Arena, city::City, club::Club, colour::Colour, competition::Competition,
+            competition_history::CompetitionHistory, continent::Continent, currency::Currency, draft::Draft,
+            injury::Injury, name::Name, nation::Nation, non_player::NonPlayer, official::Official, player::Player,
+            retired_number::RetiredNumber, staff::Staff, staff_award::StaffAward,
+            staff_preferences::StaffPreferences, stage_name::StageName, state_province::StateProvince,
+        },
+        init::{FileIndex, Header},
+    };
+
+    use super::*;
+
+    // Read a record from the start of some bytes and check that writing it gives the same bytes back, and that the
+    // fields cover all of them.
+    fn round_trip<T: BinRead<Args = ()> + Record>() {
+        let bytes: Vec<u8> = (0..65536u32).map(|i| (i * 37 % 251) as u8).collect();
+        let mut cursor = Cursor::new(bytes.as_slice());
+        let record = T::read(&mut cursor).unwrap();
+        let size = cursor.position() as usize;
+
+        assert_eq!(record.to_bytes(), bytes[..size]);
+
+        let last = T::fields().pop().unwrap();
+        assert_eq!(last.offset + last.size, size);
+    }
+
+    macro_rules! round_trip_tests {
+        ($($test:ident: $ty:ty),* $(,)?) => {
+            $(
+                #[test]
+                fn $test() {
+                    round_trip::<$ty>();
+                }
+            )*
+        };
+    }
+
+    round_trip_tests!(
+        header: Header,
+        file_index: FileIndex,
+        date: SIDate,
+        arena: Arena,
+        city: City,
+        club: Club,
+        colour: Colour,
+        competition: Competition,
+        competition_history: CompetitionHistory,
+        continent: Continent,
+        currency: Currency,
+        draft: Draft,
+        injury: Injury,
+        name: Name,
+        nation: Nation,
+        non_player: NonPlayer,
+        official: Official,
+        player: Player,
+        retired_number: RetiredNumber,
+        staff: Staff,
+        staff_award: StaffAward,
+        staff_preferences: StaffPreferences,
+        stage_name: StageName,
+        state_province: StateProvince,
+    );
+
+    #[test]
+    fn fields_have_offsets() {
+        let fields = Staff::fields();
+        let date_of_birth = fields.iter().find(|field| field.name == "date_of_birth").unwrap();
+        assert_eq!(date_of_birth, &Field { name: "date_of_birth", offset: 60, size: 5, type_name: "SIDate" });
+
+        let fields = FileIndex::fields();
+        assert_eq!(fields[2], Field { name: "b_name", offset: 8, size: 260, type_name: "Vec<u8>" });
+    }
+}
@@ -0,0 +1,233 @@
+// The graph of who likes and dislikes whom.
+
+use serde::Serialize;
+
+use crate::data::Data;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Staff,
+    Club,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Affinity {
+    Favourite,
+    Disliked,
+    Rival,
+}
+
+impl Affinity {
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Affinity::Favourite => "favourite",
+            Affinity::Disliked => "disliked",
+            Affinity::Rival => "rival",
+        };
+    }
+
+    fn dot_colour(&self) -> &'static str {
+        return match self {
+            Affinity::Favourite => "darkgreen",
+            Affinity::Disliked => "red",
+            Affinity::Rival => "orange",
+        };
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub id: i32,
+}
+
+impl Node {
+    pub fn staff(id: i32) -> Self {
+        return Self { kind: NodeKind::Staff, id };
+    }
+
+    pub fn club(id: i32) -> Self {
+        return Self { kind: NodeKind::Club, id };
+    }
+
+    // Get an ID that is unique across node kinds.
+    fn key(&self) -> String {
+        return match self.kind {
+            NodeKind::Staff => format!("s{}", self.id),
+            NodeKind::Club => format!("c{}", self.id),
+        };
+    }
+
+    // Get the readable name of the node.
+    pub fn name(&self, data: &Data) -> String {
+        return match self.kind {
+            NodeKind::Staff => match data.staff.get(&self.id) {
+                Some(s) => s.full_name(data),
+                None => String::new(),
+            },
+            NodeKind::Club => match data.clubs.get(&self.id) {
+                Some(c) => c.name(),
+                None => String::new(),
+            },
+        };
+    }
+}
+
+#[derive(Clone, Serialize)]
+pub struct Edge {
+    pub from: Node,
+    pub to: Node,
+    pub affinity: Affinity,
+}
+
+#[derive(Default)]
+pub struct RelationshipGraph {
+    pub edges: Vec<Edge>,
+}
+
+impl RelationshipGraph {
+    // Build the graph from staff preferences and club preferences.
+    pub fn new(data: &Data) -> Self {
+        let mut graph = Self::default();
+
+        for (id, person) in data.staff.iter() {
+            let preferences = match person.preferences(data) {
+                Some(p) => p,
+                None => continue,
+            };
+
+            let from = Node::staff(*id);
+            graph.add_edges(from, preferences.favourite_club_ids(), Node::club, Affinity::Favourite);
+            graph.add_edges(from, preferences.disliked_club_ids(), Node::club, Affinity::Disliked);
+            graph.add_edges(from, preferences.favourite_staff_ids(), Node::staff, Affinity::Favourite);
+            graph.add_edges(from, preferences.disliked_staff_ids(), Node::staff, Affinity::Disliked);
+        }
+
+        for (id, club) in data.clubs.iter() {
+            let from = Node::club(*id);
+            graph.add_edges(from, club.favourite_staff_ids(), Node::staff, Affinity::Favourite);
+            graph.add_edges(from, club.disliked_staff_ids(), Node::staff, Affinity::Disliked);
+            graph.add_edges(from, club.rival_ids(), Node::club, Affinity::Rival);
+        }
+
+        // Keep the exports stable between runs.
+        graph.edges.sort_by_key(|e| (e.from.kind as u8, e.from.id, e.to.kind as u8, e.to.id, e.affinity as u8));
+        return graph;
+    }
+
+    fn add_edges(&mut self, from: Node, ids: Vec<i32>, to: fn(i32) -> Node, affinity: Affinity) {
+        for id in ids {
+            self.edges.push(Edge { from, to: to(id), affinity });
+        }
+    }
+
+    // Get the affinities the node has towards others.
+    pub fn outgoing(&self, node: Node) -> Vec<&Edge> {
+        return self.edges.iter().filter(|e| e.from == node).collect();
+    }
+
+    // Get the affinities others have towards the node.
+    pub fn incoming(&self, node: Node) -> Vec<&Edge> {
+        return self.edges.iter().filter(|e| e.to == node).collect();
+    }
+
+    // Get every node that is part of an edge, in order of appearance.
+    fn nodes(&self) -> Vec<Node> {
+        let mut seen = std::collections::HashSet::new();
+        let mut nodes = Vec::new();
+
+        for edge in self.edges.iter() {
+            for node in [edge.from, edge.to] {
+                if seen.insert(node) {
+                    nodes.push(node);
+                }
+            }
+        }
+
+        return nodes;
+    }
+
+    // Export the graph in the GraphViz DOT format.
+    pub fn to_dot(&self, data: &Data) -> String {
+        let mut lines = Vec::from(["digraph relationships {".to_string()]);
+
+        for node in self.nodes() {
+            let shape = match node.kind {
+                NodeKind::Staff => "ellipse",
+                NodeKind::Club => "box",
+            };
+
+            lines.push(format!(
+                "    \"{}\" [label=\"{}\", shape={shape}];",
+                node.key(),
+                escape_dot(&node.name(data)),
+            ));
+        }
+
+        for edge in self.edges.iter() {
+            lines.push(format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\", color={}];",
+                edge.from.key(),
+                edge.to.key(),
+                edge.affinity.name(),
+                edge.affinity.dot_colour(),
+            ));
+        }
+
+        lines.push("}".to_string());
+        return lines.join("\n");
+    }
+
+    // Export the graph in the GraphML format.
+    pub fn to_graphml(&self, data: &Data) -> String {
+        let mut lines = Vec::from([
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">".to_string(),
+            "  <key id=\"kind\" for=\"node\" attr.name=\"kind\" attr.type=\"string\"/>".to_string(),
+            "  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>".to_string(),
+            "  <key id=\"affinity\" for=\"edge\" attr.name=\"affinity\" attr.type=\"string\"/>".to_string(),
+            "  <graph id=\"relationships\" edgedefault=\"directed\">".to_string(),
+        ]);
+
+        for node in self.nodes() {
+            let kind = match node.kind {
+                NodeKind::Staff => "staff",
+                NodeKind::Club => "club",
+            };
+
+            lines.push(format!(
+                "    <node id=\"{}\"><data key=\"kind\">{kind}</data><data key=\"name\">{}</data></node>",
+                node.key(),
+                escape_xml(&node.name(data)),
+            ));
+        }
+
+        for edge in self.edges.iter() {
+            lines.push(format!(
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"affinity\">{}</data></edge>",
+                edge.from.key(),
+                edge.to.key(),
+                edge.affinity.name(),
+            ));
+        }
+
+        lines.push("  </graph>".to_string());
+        lines.push("</graphml>".to_string());
+        return lines.join("\n");
+    }
+}
+
+fn escape_dot(string: &str) -> String {
+    return string.replace('\\', "\\\\").replace('"', "\\\"");
+}
+
+fn escape_xml(string: &str) -> String {
+    return string
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;");
+}
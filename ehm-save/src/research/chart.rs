@@ -0,0 +1,252 @@
+// Generating the attribute chart from the research data.
+// For every current ability the centre of each in-game value is the median raw value found for it. The boundaries
+// between two values lie halfway between their centres. Values and current abilities without findings are
+// interpolated from their neighbours, which the confidence report points out.
+
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+use crate::{
+    data::chart_check::{MAX_ATTRIBUTE, MAX_CA, MIN_CA, validate_chart}, globals::attr_chart::AttributeChart,
+    research::AttributeData,
+};
+
+// The distance between two in-game values if there is nothing to measure it with.
+const DEFAULT_STEP: f64 = 8.5;
+
+pub struct Chart {
+    rows: Vec<Row>,
+}
+
+struct Row {
+    ca: i16,
+    // The lowest raw value of each in-game value from 1 to 20.
+    lower_bounds: [i32; MAX_ATTRIBUTE as usize],
+    confidence: Option<Confidence>,
+}
+
+// How well the measured findings fit a row. None if the row is interpolated from other current abilities.
+struct Confidence {
+    samples: usize,
+    values_found: usize,
+    samples_in_range: usize,
+}
+
+impl Row {
+    fn range(&self, attribute: i8) -> RangeInclusive<i8> {
+        let index = (attribute - 1) as usize;
+        let start = self.lower_bounds[index] as i8;
+        let end = match self.lower_bounds.get(index + 1) {
+            Some(next) => (next - 1) as i8,
+            None => i8::MAX,
+        };
+
+        return start..=end;
+    }
+}
+
+// Generate the chart from the findings. None if there are no findings to generate it from.
+pub fn generate(attr_data: &AttributeData) -> Option<Chart> {
+    let mut measured: BTreeMap<i16, Row> = BTreeMap::new();
+
+    for (ca, ca_data) in attr_data.range(MIN_CA..=MAX_CA) {
+        let mut centres = [None; MAX_ATTRIBUTE as usize];
+        let mut samples = 0;
+
+        for (attribute, raw_counts) in ca_data.range(1..=MAX_ATTRIBUTE) {
+            centres[(attribute - 1) as usize] = median(raw_counts);
+            samples += raw_counts.values().sum::<usize>();
+        }
+
+        let values_found = centres.iter().filter(|c| c.is_some()).count();
+        if values_found == 0 {
+            continue;
+        }
+
+        let centres = fill_gaps(&centres, DEFAULT_STEP);
+        let mut lower_bounds = [i8::MIN as i32; MAX_ATTRIBUTE as usize];
+        for i in 1..lower_bounds.len() {
+            lower_bounds[i] = ((centres[i - 1] + centres[i]) / 2.0).ceil() as i32;
+        }
+
+        let mut row = Row {
+            ca: *ca,
+            lower_bounds: make_contiguous(lower_bounds),
+            confidence: None,
+        };
+
+        let samples_in_range = ca_data.range(1..=MAX_ATTRIBUTE)
+            .map(|(attribute, raw_counts)| {
+                let range = row.range(*attribute);
+                raw_counts.iter().filter(|(raw, _)| range.contains(*raw)).map(|(_, count)| count).sum::<usize>()
+            })
+            .sum();
+
+        row.confidence = Some(Confidence { samples, values_found, samples_in_range });
+        measured.insert(*ca, row);
+    }
+
+    if measured.is_empty() {
+        return None;
+    }
+
+    let interpolated: Vec<Row> = (MIN_CA..=MAX_CA)
+        .filter(|ca| !measured.contains_key(ca))
+        .map(|ca| Row { ca, lower_bounds: interpolate_ca(&measured, ca), confidence: None })
+        .collect();
+
+    let mut rows: Vec<Row> = measured.into_values().chain(interpolated).collect();
+    rows.sort_by_key(|row| row.ca);
+
+    return Some(Chart { rows });
+}
+
+// The raw value that half of the findings are at or below.
+fn median(raw_counts: &BTreeMap<i8, usize>) -> Option<f64> {
+    let total: usize = raw_counts.values().sum();
+    let mut seen = 0;
+
+    for (raw, count) in raw_counts {
+        seen += count;
+        if seen * 2 >= total {
+            return Some(*raw as f64);
+        }
+    }
+
+    return None;
+}
+
+// Interpolate the missing centres between the known ones, and extrapolate them past the first and last known one.
+fn fill_gaps(centres: &[Option<f64>], default_step: f64) -> Vec<f64> {
+    let known: Vec<(usize, f64)> = centres.iter().enumerate().filter_map(|(i, c)| c.map(|c| (i, c))).collect();
+    let (first, last) = (known[0], known[known.len() - 1]);
+    let step = match first.0 == last.0 {
+        true => default_step,
+        false => (last.1 - first.1) / (last.0 - first.0) as f64,
+    };
+
+    return (0..centres.len()).map(|i| {
+        if let Some(centre) = centres[i] {
+            return centre;
+        }
+
+        let before = known.iter().rev().find(|(k, _)| *k < i);
+        let after = known.iter().find(|(k, _)| *k > i);
+
+        return match (before, after) {
+            (Some((bi, bc)), Some((ai, ac))) => bc + (ac - bc) * (i - bi) as f64 / (ai - bi) as f64,
+            (Some((bi, bc)), None) => bc + step * (i - bi) as f64,
+            (None, Some((ai, ac))) => ac - step * (ai - i) as f64,
+            (None, None) => unreachable!(),
+        };
+    }).collect();
+}
+
+// Make every range hold at least one raw value, start at the lowest raw value and fit into an i8.
+fn make_contiguous(mut lower_bounds: [i32; MAX_ATTRIBUTE as usize]) -> [i32; MAX_ATTRIBUTE as usize] {
+    let len = lower_bounds.len();
+    lower_bounds[0] = i8::MIN as i32;
+
+    for i in 1..len {
+        let highest = i8::MAX as i32 - (len - 1 - i) as i32;
+        lower_bounds[i] = lower_bounds[i].max(lower_bounds[i - 1] + 1).min(highest);
+    }
+
+    return lower_bounds;
+}
+
+// Interpolate the boundaries of a current ability without findings from the nearest measured ones.
+fn interpolate_ca(measured: &BTreeMap<i16, Row>, ca: i16) -> [i32; MAX_ATTRIBUTE as usize] {
+    let before = measured.range(..ca).next_back().map(|(_, row)| row);
+    let after = measured.range(ca..).next().map(|(_, row)| row);
+
+    let (before, after) = match (before, after) {
+        (Some(b), Some(a)) => (b, a),
+        (Some(row), None) | (None, Some(row)) => return row.lower_bounds,
+        (None, None) => unreachable!(),
+    };
+
+    let weight = (ca - before.ca) as f64 / (after.ca - before.ca) as f64;
+    let mut lower_bounds = [0; MAX_ATTRIBUTE as usize];
+    for (i, bound) in lower_bounds.iter_mut().enumerate() {
+        let (b, a) = (before.lower_bounds[i] as f64, after.lower_bounds[i] as f64);
+        *bound = (b + (a - b) * weight).round() as i32;
+    }
+
+    return make_contiguous(lower_bounds);
+}
+
+impl Chart {
+    pub fn to_attribute_chart(&self) -> AttributeChart {
+        return self.rows.iter()
+            .map(|row| (row.ca, (1..=MAX_ATTRIBUTE).map(|attribute| (attribute, row.range(attribute))).collect()))
+            .collect();
+    }
+
+    // The chart as the source of globals/attr_chart.rs.
+    pub fn to_rust(&self) -> String {
+        let mut lines = vec![
+            String::from("// Generated by `ehm-save-viewer research`. Regenerate it instead of editing it by hand."),
+            String::from("use std::{collections::HashMap, ops::RangeInclusive};"),
+            String::from("use lazy_static::lazy_static;"),
+            String::new(),
+            String::from("pub type AttributeChart = HashMap<i16, HashMap<i8, RangeInclusive<i8>>>;"),
+            String::new(),
+            String::from("lazy_static! {"),
+            String::from("    pub static ref ATTRIBUTE_CHART: AttributeChart = {"),
+            String::from("        HashMap::from(["),
+        ];
+
+        for row in self.rows.iter() {
+            lines.push(String::from("            ("));
+            lines.push(format!("                {},", row.ca));
+            lines.push(String::from("                HashMap::from(["));
+            for attribute in 1..=MAX_ATTRIBUTE {
+                let range = row.range(attribute);
+                lines.push(format!("                    ({attribute}, {}..={}),", range.start(), range.end()));
+            }
+            lines.push(String::from("                ]),"));
+            lines.push(String::from("            ),"));
+        }
+
+        lines.push(String::from("        ])"));
+        lines.push(String::from("    };"));
+        lines.push(String::from("}"));
+
+        return lines.join("\n");
+    }
+
+    // A table about how many findings each current ability is based on and how many of them the chart agrees with.
+    pub fn report(&self) -> String {
+        let mut lines = vec![
+            format!("{:>4}  {:>8}  {:>6}  {:>9}  Source", "CA", "Samples", "Values", "Agreement"),
+        ];
+
+        for row in self.rows.iter() {
+            lines.push(match &row.confidence {
+                Some(c) => format!(
+                    "{:>4}  {:>8}  {:>6}  {:>8.1}%  measured",
+                    row.ca,
+                    c.samples,
+                    format!("{}/{MAX_ATTRIBUTE}", c.values_found),
+                    c.samples_in_range as f64 * 100.0 / c.samples as f64,
+                ),
+                None => format!("{:>4}  {:>8}  {:>6}  {:>9}  interpolated", row.ca, 0, format!("0/{MAX_ATTRIBUTE}"), "-"),
+            });
+        }
+
+        let measured = self.rows.iter().filter(|row| row.confidence.is_some()).count();
+        lines.push(String::new());
+        lines.push(format!("{measured} of {} current abilities measured", self.rows.len()));
+
+        let problems = validate_chart(&self.to_attribute_chart());
+        if !problems.is_empty() {
+            lines.push(String::new());
+            lines.push(String::from("Problems of the chart:"));
+            lines.extend(problems.iter().map(|p| p.to_string()));
+        }
+
+        lines.push(String::new());
+
+        return lines.join("\n");
+    }
+}
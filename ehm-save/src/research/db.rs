@@ -1,49 +1,8 @@
 // Database parsing.
 
-use std::{
-    fs::File,
-    io::{Cursor, Read as _},
-    path::Path,
-};
-
-use crate::{
-    data::Data, globals::PARSER_GUIDE, init::parse_file, research::_AttributeData
-};
-
-pub fn _load_files(path_name: &str) -> Data {
-    let folder = Path::new(path_name);
-
-    let mut db_data = Data::default();
-    for (filename, parser) in PARSER_GUIDE.iter() {
-        let mut path_buf = folder.to_path_buf();
-        path_buf.push(filename.as_str());
-
-        let mut file = match File::open(&path_buf) {
-            Ok(f) => f,
-            Err(e) => {
-                panic!("{} - {}", path_buf.to_str().unwrap(), e);
-            }
-        };
-
-        let mut buffer = Vec::new();
-        file.read_to_end(&mut buffer).unwrap();
-
-        let file_size = buffer.len() as u64;
-        let mut cursor = Cursor::new(buffer);
-
-        parse_file(
-            &mut cursor,
-            &parser,
-            &mut db_data,
-            file_size,
-            filename.as_str(),
-        );
-    }
-
-    return db_data;
-}
+use crate::research::AttributeData;
 
-pub struct _Player {
+pub struct Player {
     pub forename: String,
     pub surname: String,
     pub age: i16,
@@ -107,7 +66,7 @@ pub struct _Player {
     pub rw: i8,
 }
 
-impl _Player {
+impl Player {
     fn _is_skater(&self) -> bool {
         let skater_positions = [self.ld, self.rd, self.lw, self.c, self.rw];
         return *skater_positions.iter().max().unwrap() >= 20;
@@ -278,7 +237,7 @@ impl _Player {
         }
     }
 
-    pub fn _create_csv_row(&self) -> String {
+    pub fn create_csv_row(&self) -> String {
         format!(
             "{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{};{}",
             self.current_ability,
@@ -315,9 +274,9 @@ impl _Player {
         )
     }
 
-    fn _add_attr(
+    fn add_attr(
         &self,
-        attr_data: &mut _AttributeData,
+        attr_data: &mut AttributeData,
         db_attr: i8,
         save_attr: i8,
     ) {
@@ -330,12 +289,12 @@ impl _Player {
             .or_insert(0) += 1;
     }
 
-    pub fn _add_to_attr_data(
+    pub fn add_to_attr_data(
         &self,
-        attr_data: &mut _AttributeData,
+        attr_data: &mut AttributeData,
     ) {
         if self._anticipation() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.anticipation_db,
                 self.anticipation_save,
@@ -343,7 +302,7 @@ impl _Player {
         }
 
         if self._balance() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.balance_db,
                 self.balance_save,
@@ -351,7 +310,7 @@ impl _Player {
         }
 
         if self._decisions() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.decisions_db,
                 self.decisions_save,
@@ -359,7 +318,7 @@ impl _Player {
         }
 
         if self._movement() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.movement_db,
                 self.movement_save,
@@ -367,7 +326,7 @@ impl _Player {
         }
 
         if self._one_on_ones() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.one_on_ones_db,
                 self.one_on_ones_save,
@@ -375,7 +334,7 @@ impl _Player {
         }
 
         if self._passing() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.passing_db,
                 self.passing_save,
@@ -383,7 +342,7 @@ impl _Player {
         }
 
         if self._positioning() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.positioning_db,
                 self.positioning_save,
@@ -391,7 +350,7 @@ impl _Player {
         }
 
         if self._reflexes() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.reflexes_db,
                 self.reflexes_save,
@@ -399,7 +358,7 @@ impl _Player {
         }
 
         if self._vision() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.vision_db,
                 self.vision_save,
@@ -407,7 +366,7 @@ impl _Player {
         }
 
         if self._blocker() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.blocker_db,
                 self.blocker_save,
@@ -415,7 +374,7 @@ impl _Player {
         }
 
         if self._checking() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.checking_db,
                 self.checking_save,
@@ -423,7 +382,7 @@ impl _Player {
         }
 
         if self._deflections() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.deflections_db,
                 self.deflections_save,
@@ -431,7 +390,7 @@ impl _Player {
         }
 
         if self._deking() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.deking_db,
                 self.deking_save,
@@ -439,7 +398,7 @@ impl _Player {
         }
 
         if self._faceoffs() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.faceoffs_db,
                 self.faceoffs_save,
@@ -447,7 +406,7 @@ impl _Player {
         }
 
         if self._fighting() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.fighting_db,
                 self.fighting_save,
@@ -455,7 +414,7 @@ impl _Player {
         }
 
         if self._glove() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.glove_db,
                 self.glove_save,
@@ -463,7 +422,7 @@ impl _Player {
         }
 
         if self._hitting() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.hitting_db,
                 self.hitting_save,
@@ -471,7 +430,7 @@ impl _Player {
         }
 
         if self._pokecheck() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.pokecheck_db,
                 self.pokecheck_save,
@@ -479,7 +438,7 @@ impl _Player {
         }
 
         if self._rebounds() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.rebounds_db,
                 self.rebounds_save,
@@ -487,7 +446,7 @@ impl _Player {
         }
 
         if self._recovery() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.recovery_db,
                 self.recovery_save,
@@ -495,7 +454,7 @@ impl _Player {
         }
 
         if self._slapshot() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.slapshot_db,
                 self.slapshot_save,
@@ -503,7 +462,7 @@ impl _Player {
         }
 
         if self._stickhandling() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.stickhandling_db,
                 self.stickhandling_save,
@@ -511,7 +470,7 @@ impl _Player {
         }
 
         if self._wristshot() != ";" {
-            self._add_attr(
+            self.add_attr(
                 attr_data,
                 self.wristshot_db,
                 self.wristshot_save,
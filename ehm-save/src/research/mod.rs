@@ -0,0 +1,166 @@
+// Code about attribute research goes here.
+
+pub mod chart;
+pub mod db;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::AtomicBool,
+};
+
+use crate::{
+    data::{Data, staff::Staff}, init::{load_database_folder, try_load_bin}
+};
+
+type AttributeData = BTreeMap<i16, BTreeMap<i8, BTreeMap<i8, usize>>>;
+
+const CSV_HEADER: &str = "Current Ability;Forename;Surname;Age;Birthplace;Nation;Second Nation;Club;Anticipation;In DB;Balance;In DB;Decisions;In DB;Off the Puck;In DB;One-on-Ones;In DB;Passing;In DB;Positioning;In DB;Reflexes;In DB;Creativity;In DB;Blocker;In DB;Checking;In DB;Deflections;In DB;Deking;In DB;Faceoffs;In DB;Fighting;In DB;Glove;In DB;Hitting;In DB;Pokecheck;In DB;Rebound Control;In DB;Recovery;In DB;Slapshot;In DB;Stickhandling;In DB;Wristshot;In DB";
+
+// Compare the players of every database folder with the same players in the save file in that folder. Write the
+// findings, the attribute chart generated from them and a report about how confident the chart is into the output
+// folder.
+pub fn research(inputs: &[PathBuf], output: &Path) -> Result<(), String> {
+    let mut attr_data = BTreeMap::new();
+    let mut rowlist = Vec::new();
+
+    for folder in inputs {
+        let db_attr = load_player_data(folder, &mut rowlist)?;
+        add_attr(&mut attr_data, db_attr);
+    }
+
+    // The players are found in no particular order.
+    rowlist.sort();
+    rowlist.insert(0, String::from(CSV_HEADER));
+
+    let chart = chart::generate(&attr_data).ok_or("No players were found in both the databases and the saves")?;
+
+    fs::create_dir_all(output).map_err(|e| e.to_string())?;
+    write_attr_research(output, "research", &attr_data)?;
+    fs::write(output.join("research.csv"), rowlist.join("\n")).map_err(|e| e.to_string())?;
+    fs::write(output.join("attr_chart.rs"), chart.to_rust()).map_err(|e| e.to_string())?;
+    fs::write(output.join("confidence.txt"), chart.report()).map_err(|e| e.to_string())?;
+
+    return Ok(());
+}
+
+fn load_player_data(folder: &Path, rowlist: &mut Vec<String>) -> Result<AttributeData, String> {
+    let save = try_load_bin(&find_save(folder)?, &AtomicBool::new(false), |_| {}).map_err(|e| e.to_string())?;
+    let db = load_database_folder(folder).map_err(|e| e.to_string())?;
+
+    return Ok(add_to_research(&save, &db, rowlist));
+}
+
+// Find the save file that was started from the database in the folder.
+fn find_save(folder: &Path) -> Result<PathBuf, String> {
+    let entries = fs::read_dir(folder).map_err(|e| format!("{} - {e}", folder.display()))?;
+    let mut saves: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("sav")))
+        .collect();
+    saves.sort();
+
+    return saves.into_iter().next().ok_or(format!("{} - There is no save file in the folder", folder.display()));
+}
+
+// Pair the players of the database with the same players in the save. Add a CSV row for every pair.
+fn add_to_research(
+    save: &Data,
+    db: &Data,
+    rowlist: &mut Vec<String>,
+) -> AttributeData {
+    let db_staff = get_staff_hash(&db);
+    let mut save_staff = get_staff_hash(save);
+
+    let mut attr_data = BTreeMap::new();
+
+    let players: Vec<db::Player> = db_staff
+        .iter()
+        .filter_map(|(id, db_person)| db_person.merge_players(&db, &save, &id, &mut save_staff))
+        .collect();
+
+    for player in players {
+        rowlist.push(player.create_csv_row());
+        player.add_to_attr_data(&mut attr_data);
+    }
+
+    return attr_data;
+}
+
+fn get_staff_hash(data: &Data) -> HashMap<[String; 6], Staff> {
+    let mut staff = HashMap::new();
+
+    for (_, person) in data.staff.iter() {
+        if person.player_data(data).is_none() {
+            continue;
+        }
+
+        let key = [
+            person.forename(data),
+            person.surname(data),
+            person.birthplace(data),
+            person.nation_name(data),
+            person.second_nation_name(data),
+            person.club_contracted_name(data),
+        ];
+
+        // Remove the entry entirely in case of duplicates.
+        let old = staff.insert(key.clone(), person.clone());
+        if old.is_some() {
+            staff.remove(&key);
+        }
+    }
+
+    return staff;
+}
+
+// Write a text and JSON file about the database findings.
+fn write_attr_research(folder: &Path, filename: &str, attr_data: &AttributeData) -> Result<(), String> {
+    let write = |path: PathBuf, contents: String| {
+        fs::write(&path, contents).map_err(|e| format!("{} - {e}", path.display()))
+    };
+
+    let attr_data_json = serde_json::to_string(attr_data).map_err(|e| e.to_string())?;
+    write(folder.join(format!("{filename}.json")), attr_data_json)?;
+    write(folder.join(format!("{filename}.txt")), sorted_attr_data(attr_data))?;
+
+    return Ok(());
+}
+
+fn sorted_attr_data(data: &AttributeData) -> String {
+    let mut sorted_data = Vec::new();
+
+    // The maps are sorted by key, so only the counts need sorting.
+    for (ca_key, ca_data) in data {
+        sorted_data.push(format!("CA: {ca_key}"));
+
+        for (save_attr_key, attr_data) in ca_data {
+            let mut data_pairs: Vec<(i8, usize)> = attr_data
+                .iter()
+                .map(|(db_attr, count)| (*db_attr, *count))
+                .collect();
+            data_pairs.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+            sorted_data.push(format!("\tDB Attribute: {save_attr_key}"));
+            for (db_attr, count) in data_pairs {
+                sorted_data.push(format!("\t\tx{count}: {db_attr}"));
+            }
+        }
+    }
+
+    return sorted_data.join("\n");
+}
+
+// Add database-specific attribute data to the main attribute data.
+fn add_attr(main: &mut AttributeData, db: AttributeData) {
+    for (ca, ca_data) in db {
+        let main_ca = main.entry(ca).or_default();
+        for (db_attr, db_attr_data) in ca_data {
+            let main_db_attr = main_ca.entry(db_attr).or_default();
+            for (save_attr, count) in db_attr_data {
+                *main_db_attr.entry(save_attr).or_default() += count;
+            }
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// User scripts for custom player columns and filters.
+// A script is a Rhai file whose name is the column name. It can use the numeric player columns as variables, with
+// the spaces replaced by underscores either as written or in lowercase, e.g. `(Slapshot + wristshot) / 2 * Off_The_Puck`.
+// A script that returns true or false can also filter the players.
+
+use std::{fmt, fs, path::Path};
+
+use lazy_static::lazy_static;
+use rhai::{AST, Dynamic, Engine, Scope};
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::{
+    data::{Data, player::{CONVERTED_ATTRIBUTES, Player}, staff::Staff},
+    views,
+};
+
+// The columns with a number that scripts can use.
+const NUMERIC_COLUMNS: [&str; 44] = [
+    "Age", "GK Rating", "LD Rating", "RD Rating", "LW Rating", "C Rating", "RW Rating", "Adaptability", "Ambition",
+    "Determination", "Loyalty", "Pressure", "Professionalism", "Sportsmanship", "Temperament", "Current Ability",
+    "Potential Ability", "Acceleration", "Aggression", "Agility", "Bravery", "Consistency", "Dirtiness", "Flair",
+    "Important Matches", "Injury Proneness", "Influence", "Natural Fitness", "Speed", "Stamina", "Strength",
+    "Teamwork", "Versatility", "Work Rate", "GK", "LD", "RD", "LW", "C", "RW", "Agitation", "Defensive Role",
+    "Offensive Role", "Pass Tendency",
+];
+
+// Stop scripts that run for too long, such as endless loops.
+const MAX_OPERATIONS: u64 = 100_000;
+
+lazy_static! {
+    // Every column a script can use, with its variable names.
+    static ref VARIABLES: Vec<(&'static str, [String; 2])> = NUMERIC_COLUMNS.iter()
+        .chain(CONVERTED_ATTRIBUTES.iter())
+        .map(|column| {
+            let name = column.replace(' ', "_");
+            (*column, [name.clone(), name.to_lowercase()])
+        })
+        .collect();
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScriptKind {
+    // A script that returns a number.
+    Column,
+    // A script that returns true or false.
+    Filter,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScriptInfo {
+    pub name: String,
+    pub kind: ScriptKind,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScriptError {
+    pub file: String,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}: {}", self.file, self.message);
+    }
+}
+
+struct Script {
+    info: ScriptInfo,
+    ast: AST,
+}
+
+pub struct Scripts {
+    engine: Engine,
+    scripts: Vec<Script>,
+}
+
+impl Default for Scripts {
+    fn default() -> Self {
+        let mut engine = Engine::new();
+        engine.set_strict_variables(true);
+        engine.set_max_operations(MAX_OPERATIONS);
+
+        return Self { engine, scripts: Vec::new() };
+    }
+}
+
+impl Scripts {
+    // Load every .rhai file in the folder. The scripts that do not compile, use unknown variables or return something
+    // other than a number or true or false are left out and reported.
+    pub fn load_folder(path: &Path) -> Result<(Self, Vec<ScriptError>), String> {
+        let mut paths: Vec<_> = fs::read_dir(path).map_err(|e| format!("{} - {e}", path.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rhai"))
+            .collect();
+        paths.sort();
+
+        let mut scripts = Self::default();
+        let mut errors = Vec::new();
+        for path in paths {
+            let file = path.file_name().unwrap().to_string_lossy().to_string();
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let result = fs::read_to_string(&path).map_err(|e| e.to_string()).and_then(|source| scripts.add(&name, &source));
+
+            if let Err(message) = result {
+                errors.push(ScriptError { file, message });
+            }
+        }
+
+        return Ok((scripts, errors));
+    }
+
+    // Compile the script and check it by running it with every variable at zero.
+    pub fn add(&mut self, name: &str, source: &str) -> Result<(), String> {
+        let mut scope = Scope::new();
+        for (_, names) in VARIABLES.iter() {
+            for variable in names {
+                scope.push_constant(variable.as_str(), 0.0);
+            }
+        }
+
+        let ast = self.engine.compile_with_scope(&scope, source).map_err(|e| e.to_string())?;
+        let result: Dynamic = self.engine.eval_ast_with_scope(&mut scope, &ast).map_err(|e| e.to_string())?;
+
+        let kind = match result.is_bool() {
+            true => ScriptKind::Filter,
+            false if result.is_float() || result.is_int() => ScriptKind::Column,
+            false => return Err(format!("The script returns {} instead of a number or true or false", result.type_name())),
+        };
+
+        self.scripts.retain(|script| script.info.name != name);
+        self.scripts.push(Script { info: ScriptInfo { name: name.to_string(), kind }, ast });
+        return Ok(());
+    }
+
+    pub fn infos(&self) -> Vec<ScriptInfo> {
+        return self.scripts.iter().map(|script| script.info.clone()).collect();
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        return self.scripts.iter().any(|script| script.info.name == name);
+    }
+
+    // Run the script for the player. None if there is no such script or it fails.
+    pub fn evaluate(&self, name: &str, scope: &mut Scope) -> Option<Dynamic> {
+        let script = self.scripts.iter().find(|script| script.info.name == name)?;
+
+        // Drop the variables the script declares so that the next script does not see them.
+        let variable_count = scope.len();
+        let result = self.engine.eval_ast_with_scope(scope, &script.ast).ok();
+        scope.rewind(variable_count);
+
+        return result;
+    }
+
+    // Get the value of a custom column for the player. Empty if the script fails.
+    pub fn column_value(&self, name: &str, scope: &mut Scope) -> Value {
+        let result = match self.evaluate(name, scope) {
+            Some(r) => r,
+            None => return json!(""),
+        };
+
+        if let Ok(b) = result.as_bool() {
+            return json!(b);
+        }
+
+        return match result.as_float() {
+            Ok(f) => json!(f),
+            Err(_) => json!(result.as_int().unwrap_or_default()),
+        };
+    }
+
+    // Check if the player passes every filter script.
+    pub fn passes(&self, filters: &[String], scope: &mut Scope) -> bool {
+        return filters.iter().all(|name| self.evaluate(name, scope).is_some_and(|r| r.as_bool().unwrap_or(false)));
+    }
+}
+
+// Get the variables of a player for the scripts.
+pub fn player_scope(data: &Data, s: &Staff, p: &Player) -> Scope<'static> {
+    let mut columns = views::player::Player::default();
+    let mut scope = Scope::new();
+
+    for (column, names) in VARIABLES.iter() {
+        columns.add_column(data, 0, column, s, p, None);
+        let value = columns.columns.pop().and_then(|v| v.as_f64()).unwrap_or_default();
+        for variable in names {
+            scope.push_constant(variable.as_str(), value);
+        }
+    }
+
+    return scope;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripts_are_checked() {
+        let mut scripts = Scripts::default();
+
+        assert!(scripts.add("Shooting", "(Slapshot + wristshot) / 2").is_ok());
+        assert!(scripts.add("Young", "age < 21").is_ok());
+        assert!(scripts.add("Typo", "Slapshoot * 2").is_err());
+        assert!(scripts.add("Text", "\"text\"").is_err());
+        assert!(scripts.add("Endless", "loop {}").is_err());
+
+        let kinds: Vec<(String, ScriptKind)> = scripts.infos().into_iter().map(|info| (info.name, info.kind)).collect();
+        assert_eq!(kinds, [(String::from("Shooting"), ScriptKind::Column), (String::from("Young"), ScriptKind::Filter)]);
+    }
+
+    #[test]
+    fn declared_variables_do_not_leak() {
+        let mut scripts = Scripts::default();
+        scripts.add("First", "let x = 1; x").unwrap();
+        scripts.add("Second", "let x = 2; x").unwrap();
+
+        let mut scope = Scope::new();
+        assert_eq!(scripts.column_value("First", &mut scope), json!(1));
+        assert_eq!(scripts.column_value("Second", &mut scope), json!(2));
+        assert!(scope.is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+// Moving players between clubs.
+// NOTE: A player on loan is kept in the squads of both the club they are contracted to and the club they play for.
+
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::data::{Data, SIDate, YearMonthDay};
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    pub staff_id: i32,
+    pub club_contracted_id: i32,
+    // The club the player is loaned to. None if the player plays for the club they are contracted to.
+    pub loan_club_id: Option<i32>,
+    pub date_joined: YearMonthDay,
+    pub contract_expires: YearMonthDay,
+}
+
+#[derive(Debug)]
+pub enum TransferError {
+    StaffNotFound(i32),
+    NotAPlayer(String),
+    ClubNotFound(i32),
+    SameClub,
+    SquadFull(String),
+    InvalidDate(YearMonthDay),
+    ContractExpiresBeforeJoining,
+}
+
+impl fmt::Display for TransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TransferError::StaffNotFound(id) => write!(f, "There is no staff with the ID {id}"),
+            TransferError::NotAPlayer(name) => write!(f, "{name} is not a player"),
+            TransferError::ClubNotFound(id) => write!(f, "There is no club with the ID {id}"),
+            TransferError::SameClub => write!(f, "A player can not be loaned to the club they are contracted to"),
+            TransferError::SquadFull(club) => write!(f, "The squad of {club} is full"),
+            TransferError::InvalidDate((y, m, d)) => write!(f, "{d}.{m}.{y} is not a date"),
+            TransferError::ContractExpiresBeforeJoining => write!(f, "The contract expires before the player joins"),
+        };
+    }
+}
+
+// Move the player to the clubs. Everything is validated before the save is changed.
+pub fn transfer(data: &mut Data, transfer: &Transfer) -> Result<(), TransferError> {
+    let person = data.staff.get(&transfer.staff_id).ok_or(TransferError::StaffNotFound(transfer.staff_id))?;
+    if person.player_data(data).is_none() {
+        return Err(TransferError::NotAPlayer(person.full_name(data)));
+    }
+
+    let contracted_id = transfer.club_contracted_id;
+    let playing_id = transfer.loan_club_id.unwrap_or(contracted_id);
+    if transfer.loan_club_id == Some(contracted_id) {
+        return Err(TransferError::SameClub);
+    }
+
+    let date_joined = to_date(transfer.date_joined)?;
+    let contract_expires = to_date(transfer.contract_expires)?;
+    if contract_expires < date_joined {
+        return Err(TransferError::ContractExpiresBeforeJoining);
+    }
+
+    let old_club_ids = [person.club_contracted_id, person.club_playing_id];
+    let new_club_ids = [contracted_id, playing_id];
+
+    // Make sure the new clubs exist and have room for the player.
+    for id in new_club_ids {
+        let club = data.clubs.get(&id).ok_or(TransferError::ClubNotFound(id))?;
+        if !club.squad_ids().contains(&transfer.staff_id) && !club.has_free_squad_slot() {
+            return Err(TransferError::SquadFull(club.name()));
+        }
+    }
+
+    for id in old_club_ids.into_iter().filter(|id| !new_club_ids.contains(id)) {
+        if let Some(club) = data.clubs.get_mut(&id) {
+            club.remove_from_squad(transfer.staff_id);
+        }
+    }
+
+    for id in new_club_ids {
+        data.clubs.get_mut(&id).unwrap().add_to_squad(transfer.staff_id);
+    }
+
+    data.staff.get_mut(&transfer.staff_id).unwrap().set_clubs(contracted_id, playing_id, date_joined, contract_expires);
+    return Ok(());
+}
+
+fn to_date((year, month, day): YearMonthDay) -> Result<SIDate, TransferError> {
+    return SIDate::from_year_month_day(year, month, day).ok_or(TransferError::InvalidDate((year, month, day)));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data::{club::{Club, SQUAD_SIZE}, player::Player, staff::Staff}, record::{Record, test_record}};
+
+    use super::*;
+
+    // A club with the ID and an empty squad.
+    fn club(id: i32) -> Club {
+        let mut club: Club = test_record(&[("id", &id.to_le_bytes())]);
+        club.remove_from_squad(0);
+        return club;
+    }
+
+    // Two clubs, and a player contracted to and playing for the first one.
+    fn data() -> Data {
+        let mut data = Data::new();
+        data.clubs.insert(1, club(1));
+        data.clubs.insert(2, club(2));
+        data.clubs.get_mut(&1).unwrap().add_to_squad(1);
+        let mut person = Staff::default();
+        person.id = 1;
+        person.club_contracted_id = 1;
+        person.club_playing_id = 1;
+        data.staff.insert(1, person);
+        data.players.insert(0, Player::default());
+        return data;
+    }
+
+    fn to(club_contracted_id: i32, loan_club_id: Option<i32>) -> Transfer {
+        return Transfer {
+            staff_id: 1,
+            club_contracted_id,
+            loan_club_id,
+            date_joined: (2008, 2, 29),
+            contract_expires: (2010, 6, 30),
+        };
+    }
+
+    fn clubs(data: &Data) -> (i32, i32) {
+        let person = &data.staff[&1];
+        return (person.club_contracted_id, person.club_playing_id);
+    }
+
+    #[test]
+    fn player_moves_to_a_free_slot() {
+        let mut data = data();
+        data.clubs.get_mut(&2).unwrap().add_to_squad(5);
+        transfer(&mut data, &to(2, None)).unwrap();
+
+        assert_eq!(clubs(&data), (2, 2));
+        assert_eq!(data.clubs[&1].squad_ids(), Vec::<i32>::new());
+        assert_eq!(data.clubs[&2].squad_ids(), [5, 1]);
+    }
+
+    #[test]
+    fn loaned_player_is_in_both_squads() {
+        let mut data = data();
+        transfer(&mut data, &to(1, Some(2))).unwrap();
+
+        assert_eq!(clubs(&data), (1, 2));
+        assert_eq!(data.clubs[&1].squad_ids(), [1]);
+        assert_eq!(data.clubs[&2].squad_ids(), [1]);
+
+        // Ending the loan takes the player out of the squad they were loaned to.
+        transfer(&mut data, &to(1, None)).unwrap();
+        assert_eq!(clubs(&data), (1, 1));
+        assert_eq!(data.clubs[&2].squad_ids(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn full_squad_is_rejected() {
+        let mut data = data();
+        for id in 0..SQUAD_SIZE as i32 {
+            data.clubs.get_mut(&2).unwrap().add_to_squad(100 + id);
+        }
+
+        assert!(matches!(transfer(&mut data, &to(2, None)), Err(TransferError::SquadFull(_))));
+        assert!(matches!(transfer(&mut data, &to(1, Some(2))), Err(TransferError::SquadFull(_))));
+        assert_eq!(clubs(&data), (1, 1));
+        assert_eq!(data.clubs[&1].squad_ids(), [1]);
+    }
+
+    #[test]
+    fn dates_and_clubs_are_checked() {
+        let mut data = data();
+        let invalid = Transfer { date_joined: (2007, 2, 29), ..to(2, None) };
+        assert!(matches!(transfer(&mut data, &invalid), Err(TransferError::InvalidDate((2007, 2, 29)))));
+
+        let expired = Transfer { contract_expires: (2008, 2, 28), ..to(2, None) };
+        assert!(matches!(transfer(&mut data, &expired), Err(TransferError::ContractExpiresBeforeJoining)));
+
+        assert!(matches!(transfer(&mut data, &to(1, Some(1))), Err(TransferError::SameClub)));
+        assert!(matches!(transfer(&mut data, &to(3, None)), Err(TransferError::ClubNotFound(3))));
+        let unknown = Transfer { staff_id: 9, ..to(2, None) };
+        assert!(matches!(transfer(&mut data, &unknown), Err(TransferError::StaffNotFound(9))));
+        assert_eq!(clubs(&data), (1, 1));
+    }
+
+    #[test]
+    fn dates_have_the_leap_year_flag() {
+        // The day of the year, the year and the flag.
+        assert_eq!(to_date((2008, 2, 29)).unwrap().to_bytes(), [59, 0, 0xd8, 0x07, 1]);
+        assert_eq!(to_date((2007, 3, 1)).unwrap().to_bytes(), [59, 0, 0xd7, 0x07, 0]);
+    }
+}
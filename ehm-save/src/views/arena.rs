@@ -0,0 +1,99 @@
+// The arena browser.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{data::{self, Data, arena::RinkSize}, views::NamedId};
+
+// The columns the arena browser can sort by.
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub enum SortColumn {
+    Name,
+    City,
+    Nation,
+    Capacity,
+    #[serde(rename = "Seating Capacity")]
+    SeatingCapacity,
+    #[serde(rename = "Expansion Capacity")]
+    ExpansionCapacity,
+}
+
+#[derive(Serialize)]
+pub struct Arena {
+    pub id: i32,
+    pub name: String,
+    pub city: Option<NamedId>,
+    pub nation: Option<NamedId>,
+    pub capacity: i32,
+    pub seating_capacity: i32,
+    pub expansion_capacity: i32,
+    pub rink_size: RinkSize,
+    pub ice_condition: i8,
+    pub nearby_arena: Option<NamedId>,
+    // The clubs that play their home games in the arena.
+    pub clubs: Vec<NamedId>,
+    // The nations that use the arena as their national stadium.
+    pub national_teams: Vec<NamedId>,
+}
+
+impl Arena {
+    pub fn new(arena: &data::arena::Arena, data: &Data) -> Self {
+        let city = data.cities.get(&arena.city_id);
+        let nation = city.and_then(|c| c.nation(data));
+
+        let mut clubs: Vec<NamedId> = data.clubs.iter()
+            .filter(|(_, club)| club.stadium_id == arena.id)
+            .map(|(id, club)| (*id, club.name()))
+            .collect();
+        clubs.sort_by(|a, b| a.1.cmp(&b.1));
+
+        let mut national_teams: Vec<NamedId> = data.nations.iter()
+            .filter(|(_, nation)| nation.national_stadium_id == arena.id)
+            .map(|(id, nation)| (*id, nation.name()))
+            .collect();
+        national_teams.sort_by(|a, b| a.1.cmp(&b.1));
+
+        return Self {
+            id: arena.id,
+            name: arena.name(),
+            city: city.map(|c| (c.id, c._name())),
+            nation: nation.map(|n| (n.id, n.name())),
+            capacity: arena.capacity,
+            seating_capacity: arena.seating_capacity,
+            expansion_capacity: arena.expansion_capacity,
+            rink_size: arena.rink_size(),
+            ice_condition: arena.ice_condition,
+            nearby_arena: data.arenas.get(&arena.nearby_stadium_id).map(|a| (a.id, a.name())),
+            clubs,
+            national_teams,
+        };
+    }
+
+    // The ID of the nation the arena is in, or -1 if it is unknown.
+    pub fn nation_id(&self) -> i32 {
+        return match &self.nation {
+            Some((id, _)) => *id,
+            None => -1,
+        };
+    }
+
+    // Compare two arenas by a column of the arena browser.
+    pub fn compare(&self, other: &Self, sort_by: SortColumn) -> Ordering {
+        return match sort_by {
+            SortColumn::Name => self.name.cmp(&other.name),
+            SortColumn::City => named(&self.city).cmp(named(&other.city)),
+            SortColumn::Nation => named(&self.nation).cmp(named(&other.nation)),
+            SortColumn::Capacity => self.capacity.cmp(&other.capacity),
+            SortColumn::SeatingCapacity => self.seating_capacity.cmp(&other.seating_capacity),
+            SortColumn::ExpansionCapacity => self.expansion_capacity.cmp(&other.expansion_capacity),
+        };
+    }
+}
+
+fn named(named_id: &Option<NamedId>) -> &str {
+    return match named_id {
+        Some((_, name)) => name.as_str(),
+        None => "",
+    };
+}
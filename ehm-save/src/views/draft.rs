@@ -0,0 +1,24 @@
+// The drafts in the save.
+
+use serde::Serialize;
+
+use crate::{data::{self, Data}, views::NamedId};
+
+#[derive(Serialize)]
+pub struct Draft {
+    pub id: i32,
+    pub name: String,
+    pub competition: Option<NamedId>,
+    pub extinct: bool,
+}
+
+impl Draft {
+    pub fn new(draft: &data::draft::Draft, data: &Data) -> Self {
+        return Self {
+            id: draft.id,
+            name: draft.name(),
+            competition: data.competitions.get(&draft.comp_id).map(|c| (c.id, c.name())),
+            extinct: draft.is_extinct(),
+        };
+    }
+}
@@ -0,0 +1,63 @@
+// Player counts by birthplace.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::data::{Data, player::Player, staff::Staff};
+
+#[derive(Serialize)]
+pub struct PlaceCount {
+    pub id: i32,
+    pub name: String,
+    pub nation: String,
+    pub count: usize,
+}
+
+#[derive(Serialize)]
+pub struct BirthplaceCounts {
+    pub cities: Vec<PlaceCount>,
+    pub states_provinces: Vec<PlaceCount>,
+}
+
+impl BirthplaceCounts {
+    pub fn new(data: &Data, players: &[(&Staff, Player)]) -> Self {
+        let mut cities: HashMap<i32, PlaceCount> = HashMap::new();
+        let mut states_provinces: HashMap<i32, PlaceCount> = HashMap::new();
+
+        for (person, _) in players {
+            let town = match person.birth_town(data) {
+                Some(t) => t,
+                None => continue,
+            };
+
+            let nation = match town.nation(data) {
+                Some(n) => n.name(),
+                None => String::new(),
+            };
+
+            cities.entry(town.id)
+                .or_insert_with(|| PlaceCount { id: town.id, name: town._name(), nation: nation.clone(), count: 0 })
+                .count += 1;
+
+            if let Some(state) = town.state(data) {
+                states_provinces.entry(state.id)
+                    .or_insert_with(|| PlaceCount { id: state.id, name: state.name(), nation, count: 0 })
+                    .count += 1;
+            }
+        }
+
+        return Self {
+            cities: sorted_counts(cities),
+            states_provinces: sorted_counts(states_provinces),
+        };
+    }
+}
+
+// Most common places first.
+fn sorted_counts(counts: HashMap<i32, PlaceCount>) -> Vec<PlaceCount> {
+    let mut counts: Vec<PlaceCount> = counts.into_values().collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+
+    return counts;
+}
@@ -0,0 +1,45 @@
+// The injury catalogue. The injuries people currently have are in records that have not been decoded yet, so they are
+// not linked to players.
+
+use serde::Serialize;
+
+use crate::data;
+
+#[derive(Serialize)]
+pub struct Injury {
+    pub id: i16,
+    pub name: String,
+    pub name_dative: String,
+    pub name_genetive: String,
+    pub name_special_nominative: String,
+    pub minimum_days_out: i32,
+    pub maximum_days_out: i32,
+    pub category: i8,
+    pub chance: i8,
+    pub inactive_ratio: i8,
+    pub recurring: bool,
+    pub severity: i8,
+    pub cause: i8,
+}
+
+impl Injury {
+    pub fn new(injury: &data::injury::Injury) -> Self {
+        let (minimum_days_out, maximum_days_out) = injury.days_out_range();
+
+        return Self {
+            id: injury.id,
+            name: injury.name(),
+            name_dative: injury.name_dative(),
+            name_genetive: injury.name_genetive(),
+            name_special_nominative: injury.name_special_nominative(),
+            minimum_days_out,
+            maximum_days_out,
+            category: injury.category,
+            chance: injury.chance,
+            inactive_ratio: injury.inactive_ratio,
+            recurring: injury.is_recurring(),
+            severity: injury.severity,
+            cause: injury.cause,
+        };
+    }
+}
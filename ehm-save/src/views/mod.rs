@@ -0,0 +1,74 @@
+// Data for the frontend.
+
+pub mod arena;
+pub mod draft;
+pub mod geo;
+pub mod injury;
+pub mod player;
+pub mod player_detail;
+pub mod player_page;
+pub mod relationships;
+pub mod text_field;
+
+use crate::{data::Data, filters::PlayerFilters, scripts::{Scripts, player_scope}};
+
+// An entity reference with a readable name.
+pub type NamedId = (i32, String);
+
+// Get the players that match the filters with the columns in the headers.
+pub fn players(
+    data: &Data,
+    headers: &[String],
+    filters: &PlayerFilters,
+    display_currency: Option<i32>,
+    scripts: &Scripts,
+) -> Vec<player::Player> {
+    return data.filtered_players(filters).into_iter()
+        .filter(|(person, p)| filters.scripts.is_empty() || scripts.passes(&filters.scripts, &mut player_scope(data, person, p)))
+        .enumerate()
+        .map(|(counter, (person, p))| person.create_player_view(p, data, headers, counter, display_currency, scripts))
+        .collect();
+}
+
+// Get everything about one player. Return None if the person is not a player.
+pub fn player_detail(data: &Data, staff_id: i32, display_currency: Option<i32>) -> Option<player_detail::PlayerDetail> {
+    let person = data.staff.get(&staff_id)?;
+    let p = person.player_data(data)?;
+
+    return Some(person.create_player_detail(p, data, display_currency));
+}
+
+// Get the possible ingame dates as day.month.year.
+pub fn ingame_dates(data: &Data) -> [String; 2] {
+    return data.date_range.each_ref().map(|date| {
+        let (year, month, day) = date.to_year_month_day();
+        format!("{day}.{month}.{year}")
+    });
+}
+
+// Get all nations and their IDs, sorted by name.
+pub fn nations(data: &Data) -> Vec<NamedId> {
+    let mut nations: Vec<NamedId> = data.nations.iter().map(|(id, nation)| (*id, nation.name())).collect();
+    nations.sort_by(|a, b| a.1.cmp(&b.1));
+
+    nations.push((-1, "N/A".to_string()));
+    return nations;
+}
+
+// Get all clubs and their IDs, sorted by name.
+pub fn clubs(data: &Data) -> Vec<NamedId> {
+    let mut clubs: Vec<NamedId> = data.clubs.iter().map(|(id, club)| (*id, club.name())).collect();
+    clubs.sort_by(|a, b| a.1.cmp(&b.1));
+
+    clubs.push((-1, "No Club".to_string()));
+    return clubs;
+}
+
+// Get all competitions and their IDs, sorted by name.
+pub fn competitions(data: &Data) -> Vec<NamedId> {
+    let mut comps: Vec<NamedId> = data.competitions.iter().map(|(id, comp)| (*id, comp.name())).collect();
+    comps.sort_by(|a, b| a.1.cmp(&b.1));
+
+    comps.push((-1, "No Competition".to_string()));
+    return comps;
+}
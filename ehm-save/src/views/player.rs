@@ -1,28 +1,51 @@
 // The player view.
 
+use std::collections::HashMap;
+
 use serde::Serialize;
 use serde_json::json;
 
 use crate::data::{self, Data, staff::Staff};
 
-#[derive(Default, Serialize)]
+#[derive(Clone, Default, Serialize)]
 pub struct Player {
+    pub id: i32,
     pub columns: Vec<serde_json::Value>,
     pub forename: String,
     pub surname: String,
     pub date_of_birth: (i16, u8, u8),
     pub positions: Vec<u8>,
+    // The money columns in the game's base currency, so that players shown in different currencies sort right.
+    #[serde(skip)]
+    pub amounts: HashMap<String, i32>,
 }
 
 impl Player {
-    pub fn add_column(&mut self, data: &Data, counter: usize, header: &str, s: &Staff, p: &data::player::Player) {
+    pub fn add_column(
+        &mut self,
+        data: &Data,
+        counter: usize,
+        header: &str,
+        s: &Staff,
+        p: &data::player::Player,
+        display_currency: Option<i32>,
+    ) {
+        if let Some(amount) = s.money_amount(data, header) {
+            self.amounts.insert(header.to_string(), amount);
+        }
+
         self.columns.push(match header {
             "Random" => json!(counter),
             "Name" => json!(s.full_name(data)),
             "Nation" => json!(s.nation_name(data)),
             "Second Nation" => json!(s.second_nation_name(data)),
-            "Club Contracted" => json!(s.club_contracted_name(data).unwrap()),
-            "Club Playing" => json!(s.club_playing_name(data).unwrap()),
+            "Club Contracted" => json!(s.club_contracted_name(data)),
+            "Club Playing" => json!(s.club_playing_name(data)),
+            "Estimated Wage" => json!(s.estimated_wage_text(data, display_currency)),
+            "Weekly Wage" => json!(s.estimated_wage_weekly_text(data, display_currency)),
+            "Estimated Value" => json!(s.estimated_value_text(data, display_currency)),
+            "Club Cash" => json!(s.club_cash_text(data, display_currency)),
+            "Club Budget" => json!(s.club_player_budget_text(data, display_currency)),
             "Age" => json!(s.age),
             "Birthday" => json!(format!("{}.{}.{}", self.date_of_birth.2, self.date_of_birth.1, self.date_of_birth.0)),
             "Birth Place" => json!(s.birthplace(data)),
@@ -0,0 +1,164 @@
+// The player profile view.
+
+use serde::Serialize;
+
+use crate::{data::YearMonthDay, views::NamedId};
+
+#[derive(Default, Serialize)]
+pub struct PlayerDetail {
+    pub id: i32,
+    pub forename: String,
+    pub surname: String,
+    pub date_of_birth: YearMonthDay,
+    pub age: i16,
+    pub birthplace: Option<Birthplace>,
+    pub nation: Option<NamedId>,
+    pub second_nation: Option<NamedId>,
+    pub declared_nation: Option<NamedId>,
+    pub club_contracted: Option<NamedId>,
+    pub club_playing: Option<NamedId>,
+    pub contract: Contract,
+    pub preferences: Option<Preferences>,
+    pub money: Money,
+
+    pub estimated_wage: i32,
+    pub estimated_wage_weekly: i32,
+    pub estimated_value: i32,
+    pub international_apps: u8,
+    pub international_goals: u8,
+    pub international_assists: u8,
+    pub stanley_cups_won: i8,
+
+    pub current_ability: i16,
+    pub potential_ability: i16,
+    pub home_reputation: i16,
+    pub current_reputation: i16,
+    pub world_reputation: i16,
+    pub squad_number: i8,
+    pub international_squad_number: i8,
+    pub favourite_number: u8,
+    pub handedness: i8,
+    pub height: u8,
+    pub weight: u8,
+    pub morale: i8,
+    pub goalie_style: i8,
+    pub junior_preference: i8,
+    pub position: String,
+    pub positions: Vec<u8>,
+
+    // Every attribute as shown in the player table. None if the chart can not convert it.
+    pub attributes: Vec<(String, Option<i8>)>,
+    pub ratings: Vec<PositionRating>,
+}
+
+#[derive(Default, Serialize)]
+pub struct Birthplace {
+    pub city: NamedId,
+    pub state: Option<NamedId>,
+    pub nation: Option<NamedId>,
+}
+
+#[derive(Default, Serialize)]
+pub struct Contract {
+    pub date_joined_club: Option<YearMonthDay>,
+    pub contract_expires_club: Option<YearMonthDay>,
+    pub date_joined_nation: Option<YearMonthDay>,
+    pub contract_expires_nation: Option<YearMonthDay>,
+    pub first_pro_contract: Option<YearMonthDay>,
+}
+
+// Monetary values in the display currency.
+#[derive(Default, Serialize)]
+pub struct Money {
+    pub currency: Option<NamedId>,
+    pub estimated_wage: String,
+    pub estimated_wage_weekly: String,
+    pub estimated_value: String,
+    pub club_cash: String,
+    pub club_player_budget: String,
+}
+
+#[derive(Default, Serialize)]
+pub struct Preferences {
+    pub favourite_clubs: Vec<NamedId>,
+    pub disliked_clubs: Vec<NamedId>,
+    pub favourite_staff: Vec<NamedId>,
+    pub disliked_staff: Vec<NamedId>,
+}
+
+#[derive(Default, Serialize)]
+pub struct PositionRating {
+    pub position: String,
+    pub rating: f64,
+    pub score: usize,
+    pub tiers: Vec<RatingTier>,
+}
+
+// The attributes that are worth the same in a position score.
+#[derive(Default, Serialize)]
+pub struct RatingTier {
+    pub weight: usize,
+    pub attributes: Vec<(String, Option<i8>)>,
+}
+
+// The attributes listed in the profile, in the player table order.
+pub const ATTRIBUTES: &[&str] = &[
+    "Adaptability",
+    "Ambition",
+    "Determination",
+    "Loyalty",
+    "Pressure",
+    "Professionalism",
+    "Sportsmanship",
+    "Temperament",
+    "Acceleration",
+    "Aggression",
+    "Agility",
+    "Anticipation",
+    "Balance",
+    "Bravery",
+    "Consistency",
+    "Decisions",
+    "Dirtiness",
+    "Flair",
+    "Important Matches",
+    "Injury Proneness",
+    "Influence",
+    "Off The Puck",
+    "Natural Fitness",
+    "One On Ones",
+    "Speed",
+    "Passing",
+    "Positioning",
+    "Reflexes",
+    "Stamina",
+    "Strength",
+    "Teamwork",
+    "Versatility",
+    "Creativity",
+    "Work Rate",
+    "GK",
+    "LD",
+    "RD",
+    "LW",
+    "C",
+    "RW",
+    "Agitation",
+    "Blocker",
+    "Checking",
+    "Defensive Role",
+    "Deflections",
+    "Deking",
+    "Faceoffs",
+    "Fighting",
+    "Glove",
+    "Hitting",
+    "Offensive Role",
+    "Pass Tendency",
+    "Pokecheck",
+    "Rebound Control",
+    "Recovery",
+    "Slapshot",
+    "Stickhandling",
+    "Wristshot",
+];
@@ -0,0 +1,264 @@
+// Sorting and paging the player table.
+
+use std::{cmp::Ordering, sync::{Arc, Mutex}};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{data::Data, filters::PlayerFilters, scripts::Scripts, views::{self, player::Player}};
+
+// The columns where -1 means no rating.
+const RATING_COLUMNS: [&str; 6] = ["GK Rating", "LD Rating", "RD Rating", "LW Rating", "C Rating", "RW Rating"];
+
+// The columns with an amount of money, which are sorted by the amount in the base currency.
+const MONEY_COLUMNS: [&str; 5] = ["Estimated Wage", "Weekly Wage", "Estimated Value", "Club Cash", "Club Budget"];
+
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SortKey {
+    pub column: String,
+    pub ascending: bool,
+}
+
+// What decides which players are in the table and what their columns are.
+#[derive(Clone, PartialEq)]
+pub struct PlayerQuery {
+    // The generation of the data, which must change whenever the data does.
+    pub generation: u64,
+    pub headers: Vec<String>,
+    pub filters: PlayerFilters,
+    pub display_currency: Option<i32>,
+}
+
+#[derive(Serialize)]
+pub struct PlayerPage {
+    pub players: Vec<Player>,
+    // The number of players that match the query.
+    pub total: usize,
+}
+
+// The players of the last query, so that changing the page or the sorting does not filter the players again.
+#[derive(Default)]
+pub struct PlayerCache {
+    query: Option<PlayerQuery>,
+    players: Arc<Vec<Player>>,
+    sort: Vec<SortKey>,
+    // The indexes of the players in the sorted order.
+    order: Arc<Vec<usize>>,
+}
+
+impl PlayerCache {
+    // Get a page of the sorted players. All players after the offset if there is no limit. The cache is only locked to
+    // look up and store the players, so that fetches do not wait for each other's filtering and sorting.
+    pub fn page(
+        cache: &Mutex<Self>,
+        data: &Data,
+        scripts: &Scripts,
+        query: PlayerQuery,
+        sort: &[SortKey],
+        offset: usize,
+        limit: Option<usize>,
+    ) -> PlayerPage {
+        let (players, order) = {
+            let cache = cache.lock().unwrap();
+            let cached = cache.query.as_ref() == Some(&query);
+            let players = match cached {
+                true => Some(cache.players.clone()),
+                false => None,
+            };
+            let order = match cached && cache.sort == sort {
+                true => Some(cache.order.clone()),
+                false => None,
+            };
+
+            (players, order)
+        };
+
+        let players = match players {
+            Some(players) => players,
+            None => Arc::new(views::players(data, &query.headers, &query.filters, query.display_currency, scripts)),
+        };
+
+        let order = match order {
+            Some(order) => order,
+            None => {
+                let mut order: Vec<usize> = (0..players.len()).collect();
+                order.sort_by(|a, b| compare(&players[*a], &players[*b], &query.headers, sort));
+                Arc::new(order)
+            }
+        };
+
+        let limit = limit.unwrap_or(order.len());
+        let page = PlayerPage {
+            players: order.iter().skip(offset).take(limit).map(|i| players[*i].clone()).collect(),
+            total: order.len(),
+        };
+
+        *cache.lock().unwrap() = Self { query: Some(query), players, sort: sort.to_vec(), order };
+        return page;
+    }
+
+    // Forget the players, e.g. when the scripts change.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+// Compare two players by the sort keys in order.
+pub fn compare(a: &Player, b: &Player, headers: &[String], sort: &[SortKey]) -> Ordering {
+    return sort.iter().fold(Ordering::Equal, |ordering, key| {
+        ordering.then_with(|| compare_column(a, b, headers.iter().position(|h| *h == key.column), key))
+    });
+}
+
+// Compare two players by one column. The empty columns are always last. The name, position and birthday do not
+// need to be in the headers.
+fn compare_column(a: &Player, b: &Player, i: Option<usize>, key: &SortKey) -> Ordering {
+    let direction = |ordering: Ordering| match key.ascending {
+        true => ordering,
+        false => ordering.reverse(),
+    };
+
+    let column = key.column.as_str();
+    return match (column, i) {
+        ("Name", _) => direction(a.surname.cmp(&b.surname).then_with(|| a.forename.cmp(&b.forename))),
+        ("Position", _) => direction(a.positions.cmp(&b.positions)),
+        ("Birthday" | "Age", _) => direction(a.date_of_birth.cmp(&b.date_of_birth)),
+        (_, None) => Ordering::Equal,
+        (_, Some(_)) if MONEY_COLUMNS.contains(&column) => {
+            empty_last(a.amounts.get(column).copied(), b.amounts.get(column).copied(), direction)
+        }
+        (_, Some(i)) => {
+            let empty = match RATING_COLUMNS.contains(&column) {
+                true => Value::from(-1.0),
+                false => Value::from(""),
+            };
+
+            let a = Some(&a.columns[i]).filter(|v| !is_same(v, &empty));
+            let b = Some(&b.columns[i]).filter(|v| !is_same(v, &empty));
+            empty_last(a, b, direction)
+        }
+    };
+}
+
+fn empty_last<T: Compare>(a: Option<T>, b: Option<T>, direction: impl Fn(Ordering) -> Ordering) -> Ordering {
+    return match (a, b) {
+        (Some(a), Some(b)) => direction(a.compare(&b)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+}
+
+trait Compare {
+    fn compare(&self, other: &Self) -> Ordering;
+}
+
+impl Compare for i32 {
+    fn compare(&self, other: &Self) -> Ordering {
+        return self.cmp(other);
+    }
+}
+
+// Numbers come before text.
+impl Compare for &Value {
+    fn compare(&self, other: &Self) -> Ordering {
+        return match (self, other) {
+            (Value::Number(a), Value::Number(b)) => {
+                a.as_f64().unwrap_or_default().total_cmp(&b.as_f64().unwrap_or_default())
+            }
+            (Value::Number(_), _) => Ordering::Less,
+            (_, Value::Number(_)) => Ordering::Greater,
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            _ => self.to_string().cmp(&other.to_string()),
+        };
+    }
+}
+
+// Check if two values are equal, counting 1 and 1.0 as the same.
+fn is_same(a: &Value, b: &Value) -> bool {
+    return match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn player(surname: &str, columns: Vec<Value>) -> Player {
+        return Player { surname: surname.to_string(), columns, ..Player::default() };
+    }
+
+    fn key(column: &str, ascending: bool) -> SortKey {
+        return SortKey { column: column.to_string(), ascending };
+    }
+
+    fn sorted(players: &[Player], headers: &[String], sort: &[SortKey]) -> Vec<String> {
+        let mut players: Vec<&Player> = players.iter().collect();
+        players.sort_by(|a, b| compare(a, b, headers, sort));
+        return players.iter().map(|p| p.surname.clone()).collect();
+    }
+
+    #[test]
+    fn sorts_by_several_columns() {
+        let headers = vec![String::from("Age"), String::from("Weekly Wage"), String::from("GK Rating")];
+        let mut players = vec![
+            player("A", vec![json!(20), json!("75,000 kr"), json!(-1.0)]),
+            player("B", vec![json!(20), json!(""), json!(0.5)]),
+            player("C", vec![json!(20), json!("$2,000"), json!(0.7)]),
+        ];
+        players[0].date_of_birth = (1990, 1, 1);
+        players[1].date_of_birth = (1990, 1, 1);
+        players[2].date_of_birth = (1989, 1, 1);
+        // Money is sorted in the base currency, not by the numbers shown in each player's currency.
+        players[0].amounts.insert(String::from("Weekly Wage"), 10_000);
+        players[2].amounts.insert(String::from("Weekly Wage"), 2_000);
+
+        assert_eq!(sorted(&players, &headers, &[key("Weekly Wage", false)]), ["A", "C", "B"]);
+        assert_eq!(sorted(&players, &headers, &[key("Weekly Wage", true)]), ["C", "A", "B"]);
+        assert_eq!(sorted(&players, &headers, &[key("GK Rating", true)]), ["B", "C", "A"]);
+        assert_eq!(sorted(&players, &headers, &[key("GK Rating", false)]), ["C", "B", "A"]);
+        assert_eq!(sorted(&players, &headers, &[key("Age", true), key("Weekly Wage", true)]), ["C", "A", "B"]);
+        assert_eq!(sorted(&players, &headers, &[key("Age", false), key("Name", false)]), ["B", "A", "C"]);
+    }
+
+    #[test]
+    fn pages_are_cached() {
+        let query = PlayerQuery {
+            generation: 0,
+            headers: vec![String::from("Name")],
+            filters: PlayerFilters::default(),
+            display_currency: None,
+        };
+        let cached = |players: &[&str]| PlayerCache {
+            query: Some(query.clone()),
+            players: Arc::new(players.iter().map(|name| player(name, vec![json!(name)])).collect()),
+            sort: Vec::new(),
+            order: Arc::new((0..players.len()).collect()),
+        };
+        let cache = Mutex::new(cached(&["C", "A", "B"]));
+
+        let surnames = |page: PlayerPage| page.players.iter().map(|p| p.surname.clone()).collect::<Vec<String>>();
+        let data = Data::new();
+        let scripts = Scripts::default();
+
+        let page = PlayerCache::page(&cache, &data, &scripts, query.clone(), &[key("Name", true)], 1, Some(1));
+        assert_eq!(page.total, 3);
+        assert_eq!(surnames(page), ["B"]);
+        let page = PlayerCache::page(&cache, &data, &scripts, query.clone(), &[key("Name", false)], 0, None);
+        assert_eq!(surnames(page), ["C", "B", "A"]);
+
+        // A new query filters the players again, and so does changed data.
+        let new_query = PlayerQuery { display_currency: Some(1), ..query.clone() };
+        assert_eq!(PlayerCache::page(&cache, &data, &scripts, new_query, &[], 0, None).total, 0);
+
+        *cache.lock().unwrap() = cached(&["A"]);
+        let new_data = PlayerQuery { generation: 1, ..query.clone() };
+        assert_eq!(PlayerCache::page(&cache, &data, &scripts, new_data, &[], 0, None).total, 0);
+    }
+}
@@ -0,0 +1,46 @@
+// The likes and dislikes of one person or club.
+
+use serde::Serialize;
+
+use crate::{data::Data, relationships::{Affinity, Edge, Node, NodeKind, RelationshipGraph}};
+
+#[derive(Serialize)]
+pub struct Relationship {
+    pub kind: NodeKind,
+    pub id: i32,
+    pub name: String,
+    pub affinity: Affinity,
+}
+
+#[derive(Serialize)]
+pub struct Relationships {
+    // What the node thinks of others.
+    pub outgoing: Vec<Relationship>,
+    // What others think of the node.
+    pub incoming: Vec<Relationship>,
+}
+
+impl Relationships {
+    pub fn new(graph: &RelationshipGraph, node: Node, data: &Data) -> Self {
+        let outgoing = graph.outgoing(node).into_iter()
+            .map(|e| Relationship::new(e.to, e, data))
+            .collect();
+
+        let incoming = graph.incoming(node).into_iter()
+            .map(|e| Relationship::new(e.from, e, data))
+            .collect();
+
+        return Self { outgoing, incoming };
+    }
+}
+
+impl Relationship {
+    fn new(other: Node, edge: &Edge, data: &Data) -> Self {
+        return Self {
+            kind: other.kind,
+            id: other.id,
+            name: other.name(data),
+            affinity: edge.affinity,
+        };
+    }
+}
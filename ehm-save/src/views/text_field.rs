@@ -0,0 +1,11 @@
+// A renamable text field of an entity.
+
+use serde::Serialize;
+
+#[derive(Default, Serialize)]
+pub struct TextField {
+    pub field: String,
+    pub text: String,
+    // The most characters the field can hold.
+    pub max_length: usize,
+}
@@ -0,0 +1,129 @@
+// Command line subcommands that run without opening the window.
+
+use std::{path::{Path, PathBuf}, sync::atomic::AtomicBool};
+
+use ehm_save::{
+    data::{Data, chart_check::validate_chart}, globals::attr_chart::ATTRIBUTE_CHART,
+    init::{load_database_folder, try_load_bin}, research,
+};
+
+use crate::serve;
+
+const USAGE: &str = "Usage:
+    ehm-save-viewer check <save | database folder>    Check the references between the records and the attributes
+    ehm-save-viewer check-chart                       Check that the attribute chart converts every value
+    ehm-save-viewer research --out <folder> <database folder>...
+        Generate the attribute chart from databases and saves started from them
+    ehm-save-viewer serve [--port <port>] <save | database folder>
+        Serve the save as JSON on localhost";
+
+const DEFAULT_PORT: u16 = 7007;
+
+// Run the subcommand in the arguments. Return the exit code, or None if the window should be opened instead.
+pub fn run(args: &[String]) -> Option<i32> {
+    let subcommand = args.first()?;
+
+    return Some(match subcommand.as_str() {
+        "check" => match args.get(1) {
+            Some(path) => check(Path::new(path)),
+            None => usage(),
+        },
+        "check-chart" => check_chart(),
+        "research" => match args.get(1..) {
+            Some([flag, output, inputs @ ..]) if flag == "--out" && !inputs.is_empty() => research(Path::new(output), inputs),
+            _ => usage(),
+        },
+        "serve" => match args.get(1..) {
+            Some([path]) => serve(Path::new(path), DEFAULT_PORT),
+            Some([flag, port, path]) if flag == "--port" => match port.parse() {
+                Ok(port) => serve(Path::new(path), port),
+                Err(_) => usage(),
+            },
+            _ => usage(),
+        },
+        "help" | "--help" | "-h" => {
+            println!("{USAGE}");
+            0
+        }
+        _ => usage(),
+    });
+}
+
+fn usage() -> i32 {
+    eprintln!("{USAGE}");
+    return 2;
+}
+
+// Load a save file, or a database if the path is a folder.
+pub fn load(path: &Path) -> Result<Data, String> {
+    if path.is_dir() {
+        return load_database_folder(path).map_err(|e| e.to_string());
+    }
+
+    return try_load_bin(path, &AtomicBool::new(false), |_| {}).map_err(|e| e.to_string());
+}
+
+// Print the integrity problems of the save or database. Return 1 if there are any.
+fn check(path: &Path) -> i32 {
+    let data = match load(path) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("{e}");
+            return 2;
+        }
+    };
+    let mut problems: Vec<String> = data.check_integrity().iter().map(|p| p.to_string()).collect();
+    problems.extend(data.check_attributes().iter().map(|p| p.to_string()));
+
+    for problem in problems.iter() {
+        println!("{problem}");
+    }
+
+    eprintln!("{} problems found", problems.len());
+    return match problems.is_empty() {
+        true => 0,
+        false => 1,
+    };
+}
+
+// Print the problems of the attribute chart. Return 1 if there are any.
+fn check_chart() -> i32 {
+    let problems = validate_chart(&ATTRIBUTE_CHART);
+
+    for problem in problems.iter() {
+        println!("{problem}");
+    }
+
+    eprintln!("{} problems found", problems.len());
+    return match problems.is_empty() {
+        true => 0,
+        false => 1,
+    };
+}
+
+// Generate the attribute chart and its confidence report.
+fn research(output: &Path, inputs: &[String]) -> i32 {
+    let inputs: Vec<PathBuf> = inputs.iter().map(PathBuf::from).collect();
+
+    return match research::research(&inputs, output) {
+        Ok(()) => {
+            eprintln!("Wrote the attribute chart to {}", output.display());
+            0
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    };
+}
+
+// Serve the save until the process is stopped.
+fn serve(path: &Path, port: u16) -> i32 {
+    return match serve::serve(path, port) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    };
+}
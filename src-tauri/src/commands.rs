@@ -1,99 +1,90 @@
-use std::{fs::File, io::Write, sync::Mutex};
+use std::{fs::{self, File}, io::Write, sync::{Mutex, RwLock, atomic::{AtomicBool, Ordering}}};
 
-use tauri::{AppHandle, Manager as _};
-use tauri_plugin_dialog::DialogExt as _;
+use tauri::{AppHandle, Emitter as _, Manager as _};
+use tauri_plugin_dialog::{DialogExt as _, FilePath};
 
-use crate::{data::Data, init::load_bin, views};
+use ehm_save::{
+    data::{Data, SaveError, integrity::Problem}, editor, filters::PlayerFilters, geo,
+    init::{LoadError, load_database_folder, try_load_bin}, relationships::{Node, RelationshipGraph},
+    scripts::{ScriptInfo, Scripts}, transfer, views::{self, player_page::{PlayerCache, PlayerPage, PlayerQuery, SortKey}},
+};
+
+use crate::state::SaveState;
 
 #[tauri::command]
-// Get the players in the save.
-pub fn fetch_players(
+// Get a page of the players in the save, sorted by the sort keys in order. All players after the offset if there is
+// no limit.
+pub async fn fetch_players(
     handle: AppHandle,
     headers: Vec<String>,
-    birth_years: [i16; 2],
-    national_team_check: Option<bool>,
-    second_nationality_check: Option<bool>,
-    declared_check: Option<bool>,
-    include_nationalities: Vec<i32>,
-    exclude_nationalities: Vec<i32>,
-    include_clubs_contracted: Vec<i32>,
-    exclude_clubs_contracted: Vec<i32>,
-    include_clubs_playing: Vec<i32>,
-    exclude_clubs_playing: Vec<i32>,
-    include_comps_contracted: Vec<i32>,
-    exclude_comps_contracted: Vec<i32>,
-    include_comps_playing: Vec<i32>,
-    exclude_comps_playing: Vec<i32>,
-    include_nations_contracted: Vec<i32>,
-    exclude_nations_contracted: Vec<i32>,
-    include_nations_playing: Vec<i32>,
-    exclude_nations_playing: Vec<i32>,
-) -> Vec<views::player::Player> {
-    let mutex = handle.state::<Mutex<Data>>();
-    let data = mutex.lock().unwrap();
-
-    let mut counter = 0;
-    let players: Vec<views::player::Player> = data.staff.iter()
-        .filter_map(|(_, person)| {
-            let player = person.player_data(&data);
-            if player.is_none() {
-                return None;
-            }
+    filters: PlayerFilters,
+    display_currency: Option<i32>,
+    sort: Vec<SortKey>,
+    offset: usize,
+    limit: Option<usize>,
+) -> Result<PlayerPage, String> {
+    return handle.state::<SaveState>().read_generation(|data, generation| {
+        let scripts = handle.state::<RwLock<Scripts>>();
+        let scripts = scripts.read().unwrap();
 
-            let player = player.unwrap();
-            if !person.check_player_filters(
-                &data,
-                birth_years,
-                national_team_check,
-                second_nationality_check,
-                declared_check,
-                &include_nationalities,
-                &exclude_nationalities,
-                &include_clubs_contracted,
-                &exclude_clubs_contracted,
-                &include_clubs_playing,
-                &exclude_clubs_playing,
-                &include_comps_contracted,
-                &exclude_comps_contracted,
-                &include_comps_playing,
-                &exclude_comps_playing,
-                &include_nations_contracted,
-                &exclude_nations_contracted,
-                &include_nations_playing,
-                &exclude_nations_playing,
-            ) {
-                return None
-            }
+        let query = PlayerQuery { generation, headers, filters, display_currency };
+        PlayerCache::page(&handle.state::<Mutex<PlayerCache>>(), data, &scripts, query, &sort, offset, limit)
+    });
+}
 
-            let row = person.create_player_view(player, &data, &headers, counter);
-            counter += 1;
-            return Some(row);
-        })
-        .collect();
+// The loaded scripts and the errors of the ones that failed.
+type LoadedScripts = (Vec<ScriptInfo>, Vec<String>);
+
+#[tauri::command]
+// Load the custom column and filter scripts from a folder. Return the loaded scripts and the ones that failed, or
+// None if user cancelled.
+pub fn load_scripts(handle: AppHandle) -> Result<Option<LoadedScripts>, String> {
+    let folder = match handle
+        .dialog()
+        .file()
+        .blocking_pick_folder()
+    {
+        Some(p) => p,
+        None => return Ok(None),
+    };
+
+    let (scripts, errors) = Scripts::load_folder(folder.as_path().unwrap())?;
+    let infos = scripts.infos();
+
+    // Clear the cache before the players can be fetched with the new scripts.
+    let state = handle.state::<RwLock<Scripts>>();
+    let mut current = state.write().unwrap();
+    *current = scripts;
+    handle.state::<Mutex<PlayerCache>>().lock().unwrap().clear();
+    drop(current);
 
-    return players;
+    return Ok(Some((infos, errors.iter().map(|e| e.to_string()).collect())));
 }
 
 #[tauri::command]
-// Get the possible ingame dates.
-pub fn get_ingame_date(handle: AppHandle) -> [String; 2] {
-    let data = handle.state::<Mutex<Data>>();
-
-    let date_range = &data.lock().unwrap().date_range;
-    let dates = [
-        date_range[0].to_year_month_day(),
-        date_range[1].to_year_month_day(),
-    ];
+// Get everything about one player. Return None if the person is not a player.
+pub async fn get_player(
+    handle: AppHandle,
+    staff_id: i32,
+    display_currency: Option<i32>,
+) -> Result<Option<views::player_detail::PlayerDetail>, String> {
+    return handle.state::<SaveState>().read(|data| views::player_detail(data, staff_id, display_currency));
+}
 
-    return [
-        format!("{}.{}.{}", dates[0].2, dates[0].1, dates[0].0),
-        format!("{}.{}.{}", dates[1].2, dates[1].1, dates[1].0),
-    ];
+#[tauri::command]
+// Get the possible ingame dates.
+pub async fn get_ingame_date(handle: AppHandle) -> Result<[String; 2], String> {
+    return handle.state::<SaveState>().read(views::ingame_dates);
 }
 
+// Set to stop loading the save in the background.
+#[derive(Default)]
+pub struct LoadCancel(AtomicBool);
+
 #[tauri::command]
-// Load a save file. Return false if user cancelled.
-pub fn load_save(handle: AppHandle) -> bool {
+// Load a save file in the background and emit a load-progress event after each part. Return false if user cancelled.
+// The loaded data is only replaced if the whole save loads.
+pub async fn load_save(handle: AppHandle) -> Result<bool, String> {
     let filepath = match handle
         .dialog()
         .file()
@@ -102,56 +93,85 @@ pub fn load_save(handle: AppHandle) -> bool {
         .blocking_pick_file()
     {
         Some(p) => p,
-        None => return false,
+        None => return Ok(false),
     };
 
-    let data = load_bin(filepath.as_path().unwrap());
-    let old_data = handle.try_state::<Mutex<Data>>();
-    if old_data.is_none() {
-        handle.manage(Mutex::new(data));
-    }
-    else {
-        let old_data = old_data.unwrap();
-        *old_data.lock().unwrap() = data;
-    }
+    let path = filepath.as_path().unwrap().to_path_buf();
+
+    // Reset before the load starts, so that a cancel sent right away is not lost.
+    handle.state::<LoadCancel>().0.store(false, Ordering::Relaxed);
+
+    let background = handle.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        let cancel = background.state::<LoadCancel>();
+        try_load_bin(&path, &cancel.0, |progress| {
+            if let Err(e) = background.emit("load-progress", progress) {
+                eprintln!("{e}");
+            }
+        })
+    }).await.map_err(|e| e.to_string())?;
 
-    return true;
+    return match result {
+        Ok(data) => {
+            handle.state::<SaveState>().replace(data);
+            Ok(true)
+        }
+        Err(LoadError::Cancelled) => Ok(false),
+        Err(e) => Err(e.to_string()),
+    };
 }
 
 #[tauri::command]
-// Get all nations and their IDs.
-pub fn get_nations(handle: AppHandle) -> Vec<(i32, String)> {
-    let data = handle.state::<Mutex<Data>>();
+// Stop loading the save. The loaded data stays as it was.
+pub fn cancel_load(handle: AppHandle) {
+    handle.state::<LoadCancel>().0.store(true, Ordering::Relaxed);
+}
+
+#[tauri::command]
+// Load an EHM database folder. Return false if user cancelled.
+pub fn load_database(handle: AppHandle) -> Result<bool, String> {
+    let folder = match handle
+        .dialog()
+        .file()
+        .blocking_pick_folder()
+    {
+        Some(p) => p,
+        None => return Ok(false),
+    };
 
-    let mut nations: Vec<(i32, String)> = data.lock().unwrap().nations.iter().map(|(id, nation)| (*id, nation.name())).collect();
-    nations.sort_by(|a, b| a.1.cmp(&b.1));
+    let data = load_database_folder(folder.as_path().unwrap()).map_err(|e| e.to_string())?;
+    handle.state::<SaveState>().replace(data);
 
-    nations.push((-1, "N/A".to_string()));
-    return nations;
+    return Ok(true);
 }
 
 #[tauri::command]
-// Get all clubs and their IDs.
-pub fn get_clubs(handle: AppHandle) -> Vec<(i32, String)> {
-    let data = handle.state::<Mutex<Data>>();
+// Get all nations and their IDs.
+pub async fn get_nations(handle: AppHandle) -> Result<Vec<(i32, String)>, String> {
+    return handle.state::<SaveState>().read(views::nations);
+}
 
-    let mut clubs: Vec<(i32, String)> = data.lock().unwrap().clubs.iter().map(|(id, club)| (*id, club.name().unwrap())).collect();
-    clubs.sort_by(|a, b| a.1.cmp(&b.1));
+#[tauri::command]
+// Get all currencies and their IDs.
+pub async fn get_currencies(handle: AppHandle) -> Result<Vec<(i32, String)>, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let mut currencies: Vec<(i32, String)> = data.currencies.iter().map(|(id, currency)| (*id, currency.name())).collect();
+        currencies.sort_by(|a, b| a.1.cmp(&b.1));
 
-    clubs.push((-1, "No Club".to_string()));
-    return clubs;
+        currencies
+    });
 }
 
 #[tauri::command]
-// Get all competitions and their IDs.
-pub fn get_comps(handle: AppHandle) -> Vec<(i32, String)> {
-    let data = handle.state::<Mutex<Data>>();
-
-    let mut comps: Vec<(i32, String)> = data.lock().unwrap().competitions.iter().map(|(id, comp)| (*id, comp.name())).collect();
-    comps.sort_by(|a, b| a.1.cmp(&b.1));
+// Get all clubs and their IDs.
+pub async fn get_clubs(handle: AppHandle) -> Result<Vec<(i32, String)>, String> {
+    return handle.state::<SaveState>().read(views::clubs);
+}
 
-    comps.push((-1, "No Competition".to_string()));
-    return comps;
+#[tauri::command]
+// Get all competitions and their IDs.
+pub async fn get_comps(handle: AppHandle) -> Result<Vec<(i32, String)>, String> {
+    return handle.state::<SaveState>().read(views::competitions);
 }
 
 #[tauri::command]
@@ -177,4 +197,220 @@ pub fn export_to_csv(handle: AppHandle, headers: Vec<String>, players: Vec<Vec<S
 
     let mut file = File::create(filepath.as_path().unwrap()).unwrap();
     file.write_all(string.join("\n").as_bytes()).unwrap();
-}
\ No newline at end of file
+}
+
+#[tauri::command]
+// Get who the person likes and dislikes, and who likes or dislikes them.
+pub async fn get_staff_relationships(handle: AppHandle, staff_id: i32) -> Result<views::relationships::Relationships, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let graph = RelationshipGraph::new(data);
+        views::relationships::Relationships::new(&graph, Node::staff(staff_id), data)
+    });
+}
+
+#[tauri::command]
+// Get the affinities of a club in both directions.
+pub async fn get_club_relationships(handle: AppHandle, club_id: i32) -> Result<views::relationships::Relationships, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let graph = RelationshipGraph::new(data);
+        views::relationships::Relationships::new(&graph, Node::club(club_id), data)
+    });
+}
+
+#[tauri::command]
+// Export the relationship graph as GraphViz DOT ("dot") or GraphML ("graphml").
+pub fn export_relationships(handle: AppHandle, format: String) -> Result<(), String> {
+    let (filter_name, extension) = match format.as_str() {
+        "dot" => ("GraphViz DOT", "dot"),
+        "graphml" => ("GraphML", "graphml"),
+        _ => return Err(format!("'{format}' is not a graph format")),
+    };
+
+    let filepath = match handle
+        .dialog()
+        .file()
+        .add_filter(filter_name, &[extension])
+        .blocking_save_file()
+    {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+
+    let string = handle.state::<SaveState>().read(|data| {
+        let graph = RelationshipGraph::new(data);
+        match extension {
+            "dot" => graph.to_dot(data),
+            _ => graph.to_graphml(data),
+        }
+    })?;
+
+    return write_file(&filepath, string.as_bytes());
+}
+
+// Write a file picked in a dialog.
+fn write_file(filepath: &FilePath, contents: &[u8]) -> Result<(), String> {
+    let path = filepath.as_path().ok_or(format!("{filepath} is not a file"))?;
+    return fs::write(path, contents).map_err(|e| format!("{} - {e}", path.display()));
+}
+
+#[tauri::command]
+// Get how many of the filtered players were born in each city and state or province.
+pub async fn get_birthplace_counts(handle: AppHandle, filters: PlayerFilters) -> Result<views::geo::BirthplaceCounts, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let players = data.filtered_players(&filters);
+        views::geo::BirthplaceCounts::new(data, &players)
+    });
+}
+
+#[tauri::command]
+// Export the birthplaces and club arenas of the filtered players to a GeoJSON file.
+pub fn export_geojson(handle: AppHandle, filters: PlayerFilters) -> Result<(), String> {
+    let filepath = match handle
+        .dialog()
+        .file()
+        .add_filter("GeoJSON", &["geojson"])
+        .blocking_save_file()
+    {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+
+    let geojson = handle.state::<SaveState>().read(|data| {
+        let players = data.filtered_players(&filters);
+        geo::players_geojson(data, &players)
+    })?;
+
+    return write_file(&filepath, geojson.to_string().as_bytes());
+}
+
+#[tauri::command]
+// Get the arenas in the given nations (all if empty), sorted by a column.
+pub async fn get_arenas(
+    handle: AppHandle,
+    nation_ids: Vec<i32>,
+    sort_by: views::arena::SortColumn,
+    ascending: bool,
+) -> Result<Vec<views::arena::Arena>, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let mut arenas: Vec<views::arena::Arena> = data.arenas.values()
+            .map(|arena| views::arena::Arena::new(arena, data))
+            .filter(|arena| nation_ids.is_empty() || nation_ids.contains(&arena.nation_id()))
+            .collect();
+
+        arenas.sort_by(|a, b| {
+            let ordering = a.compare(b, sort_by).then_with(|| a.name.cmp(&b.name));
+            match ascending {
+                true => ordering,
+                false => ordering.reverse(),
+            }
+        });
+
+        arenas
+    });
+}
+
+#[tauri::command]
+// Get all drafts and the competitions they belong to.
+pub async fn get_drafts(handle: AppHandle) -> Result<Vec<views::draft::Draft>, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let mut drafts: Vec<views::draft::Draft> = data.drafts.values()
+            .map(|draft| views::draft::Draft::new(draft, data))
+            .collect();
+        drafts.sort_by(|a, b| a.name.cmp(&b.name));
+
+        drafts
+    });
+}
+
+#[tauri::command]
+// Get every injury in the game with its expected recovery range.
+pub async fn get_injuries(handle: AppHandle) -> Result<Vec<views::injury::Injury>, String> {
+    return handle.state::<SaveState>().read(|data| {
+        let mut injuries: Vec<views::injury::Injury> = data.injuries.values()
+            .map(views::injury::Injury::new)
+            .collect();
+        injuries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        injuries
+    });
+}
+
+#[tauri::command]
+// Get the renamable text fields of a club, competition or nation.
+pub async fn get_text_fields(handle: AppHandle, kind: String, id: i32) -> Result<Vec<views::text_field::TextField>, String> {
+    return handle.state::<SaveState>().read(|data| editor::text_fields(data, &kind, id).map_err(|e| e.to_string()))?;
+}
+
+#[tauri::command]
+// Set a text field of a club, competition or nation.
+pub fn set_text_field(handle: AppHandle, kind: String, id: i32, field: String, text: String) -> Result<(), String> {
+    handle.state::<SaveState>().write(|data| editor::set_text(data, &kind, id, &field, &text))?.map_err(|e| e.to_string())?;
+
+    return Ok(());
+}
+
+#[tauri::command]
+// Change the forename and surname of a person.
+pub fn rename_staff(handle: AppHandle, staff_id: i32, forename: String, surname: String) -> Result<(), String> {
+    handle.state::<SaveState>().write(|data| editor::rename_staff(data, staff_id, &forename, &surname))?
+        .map_err(|e| e.to_string())?;
+
+    return Ok(());
+}
+
+#[tauri::command]
+// Check the references between the records. Run before saving.
+pub async fn check_integrity(handle: AppHandle) -> Result<Vec<Problem>, String> {
+    return handle.state::<SaveState>().read(Data::check_integrity);
+}
+
+#[tauri::command]
+// Save the edited save file. Return false if user cancelled.
+// NOTE: This does not check integrity. The frontend runs `check_integrity` first and lets the user save anyway.
+pub fn save_save(handle: AppHandle) -> Result<bool, String> {
+    let state = handle.state::<SaveState>();
+    if state.read(Data::is_database)? {
+        return Err(SaveError::NotASave.to_string());
+    }
+
+    let filepath = match handle
+        .dialog()
+        .file()
+        .add_filter("EHM Save Files", &["sav"])
+        .blocking_save_file()
+    {
+        Some(p) => p,
+        None => return Ok(false),
+    };
+
+    let bin = state.write(Data::save_file)?.map_err(|e| e.to_string())?;
+
+    write_file(&filepath, &bin)?;
+    return Ok(true);
+}
+
+#[tauri::command]
+// Move a player to another club, or loan them to one.
+pub fn transfer_player(handle: AppHandle, transfer: transfer::Transfer) -> Result<(), String> {
+    handle.state::<SaveState>().write(|data| transfer::transfer(data, &transfer))?.map_err(|e| e.to_string())?;
+
+    return Ok(());
+}
+
+#[tauri::command]
+// Export the data as an EHM database folder. Return false if user cancelled.
+pub fn export_database(handle: AppHandle) -> Result<bool, String> {
+    let folder = match handle
+        .dialog()
+        .file()
+        .blocking_pick_folder()
+    {
+        Some(p) => p,
+        None => return Ok(false),
+    };
+
+    handle.state::<SaveState>().read(|data| data.save_database_folder(folder.as_path().unwrap()))?
+        .map_err(|e| e.to_string())?;
+
+    return Ok(true);
+}
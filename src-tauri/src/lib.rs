@@ -1,14 +1,12 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
-mod chars;
-mod globals;
+pub mod cli;
 mod commands;
-mod data;
-mod init;
-mod rating;
-mod research;
-mod to_bytes;
-mod views;
+mod serve;
+mod state;
 
+use std::sync::{Mutex, RwLock};
+
+use ehm_save::{scripts::Scripts, views::player_page::PlayerCache};
 use tauri::Manager as _;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -25,6 +23,11 @@ pub fn run() {
             // let data = load_bin(Path::new("C:/Users/Aleksi/Documents/Sports Interactive/EHM 2007/games/test.sav"));
             // handle.manage(data);
 
+            app.manage(state::SaveState::default());
+            app.manage(RwLock::new(Scripts::default()));
+            app.manage(Mutex::new(PlayerCache::default()));
+            app.manage(commands::LoadCancel::default());
+
             #[cfg(debug_assertions)] {
                 let window = app.get_webview_window("main").unwrap();
                 window.open_devtools();
@@ -33,12 +36,32 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::load_save,
+            commands::cancel_load,
+            commands::load_database,
             commands::fetch_players,
+            commands::load_scripts,
+            commands::get_player,
             commands::get_ingame_date,
             commands::get_nations,
             commands::get_clubs,
             commands::get_comps,
+            commands::get_currencies,
             commands::export_to_csv,
+            commands::get_staff_relationships,
+            commands::get_club_relationships,
+            commands::export_relationships,
+            commands::get_birthplace_counts,
+            commands::export_geojson,
+            commands::get_arenas,
+            commands::get_drafts,
+            commands::get_injuries,
+            commands::get_text_fields,
+            commands::set_text_field,
+            commands::rename_staff,
+            commands::check_integrity,
+            commands::save_save,
+            commands::export_database,
+            commands::transfer_player,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -2,5 +2,34 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        attach_console();
+    }
+
+    if let Some(code) = ehm_save_viewer_lib::cli::run(&args) {
+        std::process::exit(code);
+    }
+
     ehm_save_viewer_lib::run()
 }
+
+// Print the command line subcommands to the console they were run from. A Windows release build has no console of its
+// own, so without this they would print nothing.
+#[cfg(windows)]
+fn attach_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    // Fails if there is no parent console or the process already has one, which is fine either way.
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(not(windows))]
+fn attach_console() {}
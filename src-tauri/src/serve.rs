@@ -0,0 +1,160 @@
+// A read-only JSON API for a loaded save on localhost.
+//
+// GET  /players?headers=Name,Age&filters={...}&displayCurrency=1    The same players as in the viewer
+// GET  /players/<staff ID>?displayCurrency=1                        Everything about one player
+// GET  /clubs, /nations, /competitions                               IDs and names
+// GET  /date                                                         The possible ingame dates
+// POST /reload                                                       Load the save again from the disk
+
+use std::{collections::HashMap, path::{Path, PathBuf}};
+
+use serde::Serialize;
+use serde_json::json;
+use tiny_http::{Header, Method, Response, Server};
+
+use ehm_save::{data::Data, filters::PlayerFilters, scripts::Scripts, views};
+
+use crate::cli;
+
+pub struct Api {
+    path: PathBuf,
+    data: Data,
+}
+
+impl Api {
+    pub fn new(path: &Path) -> Result<Self, String> {
+        return Ok(Self { path: path.to_path_buf(), data: cli::load(path)? });
+    }
+
+    // Answer a request with a status code and a JSON body.
+    pub fn respond(&mut self, method: &Method, url: &str) -> (u16, String) {
+        let (route, query) = url.split_once('?').unwrap_or((url, ""));
+        let query: HashMap<String, String> = form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+        let segments: Vec<&str> = route.split('/').filter(|s| !s.is_empty()).collect();
+
+        let result = match (method, segments.as_slice()) {
+            (Method::Get, ["players"]) => self.players(&query),
+            (Method::Get, ["players", id]) => self.player(id, &query),
+            (Method::Get, ["clubs"]) => to_json(views::clubs(&self.data)),
+            (Method::Get, ["nations"]) => to_json(views::nations(&self.data)),
+            (Method::Get, ["competitions"]) => to_json(views::competitions(&self.data)),
+            (Method::Get, ["date"]) => to_json(views::ingame_dates(&self.data)),
+            (Method::Post, ["reload"]) => self.reload(),
+            (_, ["players"] | ["players", _] | ["clubs"] | ["nations"] | ["competitions"] | ["date"] | ["reload"]) => {
+                Err((405, String::from("Method not allowed")))
+            }
+            _ => Err((404, format!("{route} not found"))),
+        };
+
+        return match result {
+            Ok(body) => (200, body),
+            Err((status, message)) => (status, json!({ "error": message }).to_string()),
+        };
+    }
+
+    fn players(&self, query: &HashMap<String, String>) -> Result<String, (u16, String)> {
+        let headers: Vec<String> = match query.get("headers") {
+            Some(h) => h.split(',').map(String::from).collect(),
+            None => vec![String::from("Name")],
+        };
+
+        let filters: PlayerFilters = match query.get("filters") {
+            Some(f) => serde_json::from_str(f).map_err(|e| (400, format!("filters: {e}")))?,
+            None => PlayerFilters::default(),
+        };
+
+        return to_json(views::players(&self.data, &headers, &filters, display_currency(query)?, &Scripts::default()));
+    }
+
+    fn player(&self, id: &str, query: &HashMap<String, String>) -> Result<String, (u16, String)> {
+        let staff_id: i32 = id.parse().map_err(|_| (400, format!("{id} is not an ID")))?;
+
+        return match views::player_detail(&self.data, staff_id, display_currency(query)?) {
+            Some(detail) => to_json(detail),
+            None => Err((404, format!("There is no player with the ID {staff_id}"))),
+        };
+    }
+
+    fn reload(&mut self) -> Result<String, (u16, String)> {
+        self.data = cli::load(&self.path).map_err(|e| (500, e))?;
+        return to_json(views::ingame_dates(&self.data));
+    }
+}
+
+fn display_currency(query: &HashMap<String, String>) -> Result<Option<i32>, (u16, String)> {
+    return match query.get("displayCurrency") {
+        Some(id) => id.parse().map(Some).map_err(|_| (400, format!("{id} is not a currency ID"))),
+        None => Ok(None),
+    };
+}
+
+fn to_json<T: Serialize>(value: T) -> Result<String, (u16, String)> {
+    return serde_json::to_string(&value).map_err(|e| (500, e.to_string()));
+}
+
+// Serve the save on the port of localhost until the process is stopped.
+pub fn serve(path: &Path, port: u16) -> Result<(), String> {
+    let api = Api::new(path)?;
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+    eprintln!("Serving {} on http://{}", path.display(), server.server_addr());
+
+    run(api, server);
+    return Ok(());
+}
+
+// Answer the requests one at a time.
+fn run(mut api: Api, server: Server) {
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    for request in server.incoming_requests() {
+        let (status, body) = api.respond(request.method(), request.url());
+        let response = Response::from_string(body).with_status_code(status).with_header(content_type.clone());
+
+        if let Err(e) = request.respond(response) {
+            eprintln!("{e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::{Read as _, Write as _}, net::TcpStream, thread};
+
+    use super::*;
+
+    fn api() -> Api {
+        return Api { path: PathBuf::new(), data: Data::new() };
+    }
+
+    #[test]
+    fn routes() {
+        let mut api = api();
+
+        assert_eq!(api.respond(&Method::Get, "/clubs").0, 200);
+        assert_eq!(api.respond(&Method::Get, "/players?headers=Name,Age&filters={}").0, 200);
+        assert_eq!(api.respond(&Method::Get, "/players?filters=[").0, 400);
+        assert_eq!(api.respond(&Method::Get, "/players/x").0, 400);
+        assert_eq!(api.respond(&Method::Get, "/players/1").0, 404);
+        assert_eq!(api.respond(&Method::Post, "/clubs").0, 405);
+        assert_eq!(api.respond(&Method::Get, "/staff").0, 404);
+
+        // A save that can not be read keeps the loaded data.
+        api.path = PathBuf::from("missing.sav");
+        assert_eq!(api.respond(&Method::Post, "/reload").0, 500);
+        assert_eq!(api.respond(&Method::Get, "/clubs").0, 200);
+    }
+
+    #[test]
+    fn serves_on_localhost() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || run(api(), server));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(b"GET /nations HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.ends_with(r#"[[-1,"N/A"]]"#));
+    }
+}
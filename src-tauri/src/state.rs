@@ -0,0 +1,80 @@
+// The state shared by the commands.
+
+use std::sync::RwLock;
+
+use ehm_save::data::Data;
+
+// The loaded save. Reading commands share it and run at the same time, while edits lock it for themselves.
+#[derive(Default)]
+pub struct SaveState(RwLock<Loaded>);
+
+// The data and its generation, which changes whenever the data is edited or replaced.
+#[derive(Default)]
+struct Loaded {
+    data: Option<Data>,
+    generation: u64,
+}
+
+const NOT_LOADED: &str = "No save is loaded";
+
+impl SaveState {
+    // Run the function with the loaded data. Fail if nothing is loaded.
+    pub fn read<T>(&self, f: impl FnOnce(&Data) -> T) -> Result<T, String> {
+        return self.read_generation(|data, _| f(data));
+    }
+
+    // Run the function with the loaded data and its generation, so that anything worked out from the data can be
+    // kept until the data changes. Fail if nothing is loaded.
+    pub fn read_generation<T>(&self, f: impl FnOnce(&Data, u64) -> T) -> Result<T, String> {
+        let loaded = self.0.read().unwrap();
+        return match loaded.data.as_ref() {
+            Some(data) => Ok(f(data, loaded.generation)),
+            None => Err(NOT_LOADED.to_string()),
+        };
+    }
+
+    // Run the function with the loaded data and no other command using it. Fail if nothing is loaded.
+    pub fn write<T>(&self, f: impl FnOnce(&mut Data) -> T) -> Result<T, String> {
+        let mut loaded = self.0.write().unwrap();
+        loaded.generation += 1;
+        return match loaded.data.as_mut() {
+            Some(data) => Ok(f(data)),
+            None => Err(NOT_LOADED.to_string()),
+        };
+    }
+
+    // Replace the loaded data with a fully loaded one.
+    pub fn replace(&self, data: Data) {
+        let mut loaded = self.0.write().unwrap();
+        loaded.data = Some(data);
+        loaded.generation += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_without_a_save() {
+        let state = SaveState::default();
+        assert_eq!(state.read(|data| data.is_database()), Err(NOT_LOADED.to_string()));
+        assert!(state.write(|_| ()).is_err());
+
+        state.replace(Data::new());
+        assert_eq!(state.read(|data| data.is_database()), Ok(true));
+    }
+
+    #[test]
+    fn edits_change_the_generation() {
+        let state = SaveState::default();
+        state.replace(Data::new());
+        let generation = state.read_generation(|_, generation| generation).unwrap();
+
+        state.write(|_| ()).unwrap();
+        assert_eq!(state.read_generation(|_, generation| generation), Ok(generation + 1));
+
+        state.replace(Data::new());
+        assert_eq!(state.read_generation(|_, generation| generation), Ok(generation + 2));
+    }
+}
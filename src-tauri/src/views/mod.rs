@@ -1,3 +0,0 @@
-// Data for the frontend.
-
-pub mod player;
\ No newline at end of file
@@ -5,6 +5,31 @@ import { fetchPlayers } from "./table";
 
 type IncludeExclude = "Include" | "Exclude";
 
+export type PlayerFilters = {
+    birthYears: [number, number],
+    nationalTeamCheck: boolean | undefined,
+    secondNationalityCheck: boolean | undefined,
+    declaredCheck: boolean | undefined,
+    includeNationalities: number[],
+    excludeNationalities: number[],
+    includeClubsContracted: number[],
+    excludeClubsContracted: number[],
+    includeClubsPlaying: number[],
+    excludeClubsPlaying: number[],
+    includeCompsContracted: number[],
+    excludeCompsContracted: number[],
+    includeCompsPlaying: number[],
+    excludeCompsPlaying: number[],
+    includeNationsContracted: number[],
+    excludeNationsContracted: number[],
+    includeNationsPlaying: number[],
+    excludeNationsPlaying: number[],
+    scripts: string[],
+};
+
+// The filters that were applied last.
+export let FILTERS: PlayerFilters;
+
 // Create the filter elements.
 export const createFilterLayer = async (main: HTMLElement, filtersButton: HTMLButtonElement) => {
     const filterEffect = document.createElement("div");
@@ -45,6 +70,16 @@ export const createFilterLayer = async (main: HTMLElement, filtersButton: HTMLBu
     createIncludeExcludeFieldset("Include", filterContainer);
     createIncludeExcludeFieldset("Exclude", filterContainer);
 
+    const scripts = document.createElement("fieldset");
+    scripts.className = "filter-category";
+    scripts.id = "filter-scripts";
+    scripts.style.display = "none";
+
+    const scriptsLegend = document.createElement("legend");
+    scriptsLegend.textContent = "Scripts";
+    scripts.appendChild(scriptsLegend);
+    filterContainer.appendChild(scripts);
+
     const applyFiltersButton = document.createElement("button");
     applyFiltersButton.textContent = "Apply";
     applyFiltersButton.onclick = onApplyFiltersButtonClick;
@@ -60,6 +95,27 @@ export const createFilterLayer = async (main: HTMLElement, filtersButton: HTMLBu
     main.append(filterMenu, filterEffect);
 };
 
+// Replace the checkboxes of the filter scripts.
+export const setFilterScripts = (names: string[]) => {
+    const fieldset = document.getElementById("filter-scripts") as HTMLFieldSetElement;
+    fieldset.style.display = names.length === 0 ? "none" : "";
+
+    while (fieldset.children.length > 1) {
+        fieldset.removeChild(fieldset.lastChild as Node);
+    }
+
+    for (const name of names) {
+        const label = document.createElement("label");
+        const checkbox = document.createElement("input");
+        checkbox.type = "checkbox";
+        checkbox.className = "filter-script";
+        checkbox.value = name;
+
+        label.append(checkbox, name);
+        fieldset.appendChild(label);
+    }
+};
+
 // Create a section for search terms to be included.
 const createIncludeExcludeFieldset = (type: IncludeExclude, filterContainer: HTMLDivElement) => {
     const div = document.createElement("div");
@@ -361,7 +417,15 @@ export const applyFilters = async () => {
         excludeNationsPlaying.push(Number((element as HTMLSelectElement).value));
     }
 
-    await fetchPlayers(
+    const scripts = [];
+    for (const element of document.getElementsByClassName("filter-script")) {
+        const checkbox = element as HTMLInputElement;
+        if (checkbox.checked) {
+            scripts.push(checkbox.value);
+        }
+    }
+
+    FILTERS = {
         birthYears,
         nationalTeamCheck,
         secondNationalityCheck,
@@ -380,7 +444,10 @@ export const applyFilters = async () => {
         excludeNationsContracted,
         includeNationsPlaying,
         excludeNationsPlaying,
-    );
+        scripts,
+    };
+
+    await fetchPlayers(FILTERS);
 
     // Re-enable the scrollbar.
     document.body.style.overflow = "";
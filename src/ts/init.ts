@@ -1,7 +1,23 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { ask, message } from "@tauri-apps/plugin-dialog";
 import { getInGameDateText } from "./date";
-import { applyFilters, createFilterLayer } from "./filter";
-import { HEADERS, PLAYERS, sortTable } from "./table";
+import { applyFilters, createFilterLayer, FILTERS, setFilterScripts } from "./filter";
+import { clearSorting, fetchAllPlayers, HEADERS, setDisplayCurrency, sortTable } from "./table";
+
+type Progress = {
+    part: string,
+    parsed: number,
+    parts: number,
+};
+
+type ScriptInfo = {
+    name: string,
+    kind: "column" | "filter",
+};
+
+// The number of headers before the custom columns of the scripts.
+const BUILT_IN_HEADERS = HEADERS.length;
 
 // Replace the save-loading start page with the player table.
 const createPlayerView = async () => {
@@ -15,10 +31,10 @@ const createPlayerView = async () => {
     exportToCsvButton.textContent = "Export to CSV";
     exportToCsvButton.onclick = async () => {
         const csv: string[][] = [];
-        for (const player of PLAYERS) {
+        for (const player of await fetchAllPlayers()) {
             const row: string[] = [];
             for (const cell of player.columns) {
-                row.push(cell.toString());
+                row.push(cell === null ? "" : cell.toString());
             }
             csv.push(row);
         }
@@ -29,10 +45,58 @@ const createPlayerView = async () => {
         });
     };
 
+    const exportToGeoJsonButton = document.createElement("button");
+    exportToGeoJsonButton.textContent = "Export to GeoJSON";
+    exportToGeoJsonButton.onclick = async () => {
+        await invoke("export_geojson", { "filters": FILTERS });
+    };
+
     const loadSaveButton = document.createElement("button");
     loadSaveButton.textContent = "Load Save";
     loadSaveButton.onclick = loadSave;
 
+    const openDatabaseButton = document.createElement("button");
+    openDatabaseButton.textContent = "Open Database…";
+    openDatabaseButton.onclick = openDatabase;
+
+    const saveButton = document.createElement("button");
+    saveButton.textContent = "Save As";
+    saveButton.onclick = async () => {
+        // Check the references before saving.
+        const problems = await invoke("check_integrity") as unknown[];
+        if (problems.length !== 0) {
+            const save = await ask(`The save has ${problems.length} integrity problems. Save anyway?`, {
+                title: "Integrity Problems",
+                kind: "warning",
+            });
+            if (!save) { return; }
+        }
+
+        try {
+            await invoke("save_save");
+        }
+        catch (e) {
+            await message(e as string, { title: "Save Failed", kind: "error" });
+        }
+    };
+
+    const exportDatabaseButton = document.createElement("button");
+    exportDatabaseButton.textContent = "Export Database…";
+    exportDatabaseButton.onclick = async () => {
+        try {
+            await invoke("export_database");
+        }
+        catch (e) {
+            await message(e as string, { title: "Exporting the Database Failed", kind: "error" });
+        }
+    };
+
+    const loadScriptsButton = document.createElement("button");
+    loadScriptsButton.textContent = "Load Scripts…";
+    loadScriptsButton.onclick = loadScripts;
+
+    const currencySelect = await createCurrencySelect();
+
     const filtersButton = document.createElement("button");
     filtersButton.textContent = "Filters";
 
@@ -58,6 +122,20 @@ const createPlayerView = async () => {
     const tr = document.createElement("tr");
     tr.id = "headers";
 
+    thead.appendChild(tr);
+    table.append(thead, tbody);
+
+    main.append(loadSaveButton, openDatabaseButton, saveButton, exportDatabaseButton, exportToCsvButton, exportToGeoJsonButton, loadScriptsButton, inGameDate, currencySelect, filtersButton, prevButton, pageNumbers, nextButton, table);
+    await createFilterLayer(main, filtersButton);
+
+    createHeaders();
+};
+
+// Create the header row of the player table.
+const createHeaders = () => {
+    const tr = document.getElementById("headers") as HTMLTableRowElement;
+    tr.innerHTML = "";
+
     for (const header of HEADERS) {
         const th = document.createElement("th");
         th.textContent = header;
@@ -65,13 +143,63 @@ const createPlayerView = async () => {
         tr.appendChild(th);
     }
 
-    thead.appendChild(tr);
-    table.append(thead, tbody);
+    createSortingScripts();
+};
 
-    main.append(loadSaveButton, exportToCsvButton, inGameDate, filtersButton, prevButton, pageNumbers, nextButton, table);
-    await createFilterLayer(main, filtersButton);
+// Load the scripts for custom columns and filters from a folder.
+const loadScripts = async () => {
+    let result: [ScriptInfo[], string[]] | null;
+    try {
+        result = await invoke("load_scripts");
+    }
+    catch (e) {
+        await message(e as string, { title: "Loading the Scripts Failed", kind: "error" });
+        return;
+    }
 
-    createSortingScripts();
+    if (result === null) { return; }
+
+    const [scripts, errors] = result;
+    if (errors.length !== 0) {
+        await message(errors.join("\n\n"), { title: "Some Scripts Failed", kind: "warning" });
+    }
+
+    // Show the columns at the end of the table.
+    HEADERS.splice(BUILT_IN_HEADERS);
+    for (const script of scripts.filter((s) => s.kind === "column")) {
+        HEADERS.push(script.name);
+    }
+
+    createHeaders();
+    clearSorting();
+    setFilterScripts(scripts.filter((s) => s.kind === "filter").map((s) => s.name));
+    await applyFilters();
+};
+
+// Create the select for the currency money is shown in.
+const createCurrencySelect = async (): Promise<HTMLSelectElement> => {
+    const select = document.createElement("select");
+    select.id = "display-currency";
+
+    const clubCurrency = document.createElement("option");
+    clubCurrency.value = "";
+    clubCurrency.textContent = "Club Currency";
+    select.appendChild(clubCurrency);
+
+    const currencies = await invoke("get_currencies") as [number, string][];
+    for (const [id, name] of currencies) {
+        const option = document.createElement("option");
+        option.value = id.toString();
+        option.textContent = name;
+        select.appendChild(option);
+    }
+
+    select.onchange = async () => {
+        setDisplayCurrency(select.value === "" ? null : Number(select.value));
+        await applyFilters();
+    };
+
+    return select;
 };
 
 const createSortingScripts = () => {
@@ -79,15 +207,54 @@ const createSortingScripts = () => {
 
     for (let i = 0; i < tr.children.length; i++) {
         const child = tr.children[i] as HTMLTableCellElement;
-        child.onclick = () => {
-            sortTable(i);
+        child.onclick = async (e) => {
+            await sortTable(i, e.shiftKey);
         };
     }
 };
 
-// Load a save.
+// Load a save and show the progress until it is loaded.
 const loadSave = async () => {
-    let success = await invoke("load_save");
+    const overlay = document.createElement("div");
+    overlay.id = "load-progress";
+
+    const progressBar = document.createElement("progress");
+    progressBar.value = 0;
+
+    const partName = document.createElement("span");
+
+    const cancelButton = document.createElement("button");
+    cancelButton.textContent = "Cancel";
+    cancelButton.onclick = async () => {
+        await invoke("cancel_load");
+    };
+
+    overlay.append(progressBar, partName, cancelButton);
+
+    const unlisten = await listen<Progress>("load-progress", (event) => {
+        // Only show the progress once a file has been picked.
+        if (!overlay.isConnected) {
+            document.body.appendChild(overlay);
+        }
+
+        progressBar.max = event.payload.parts;
+        progressBar.value = event.payload.parsed;
+        partName.textContent = event.payload.part;
+    });
+
+    let success: boolean;
+    try {
+        success = await invoke("load_save");
+    }
+    catch (e) {
+        await message(e as string, { title: "Loading the Save Failed", kind: "error" });
+        return;
+    }
+    finally {
+        unlisten();
+        overlay.remove();
+    }
+
     if (!success) { return; }
 
     await createPlayerView();
@@ -96,10 +263,28 @@ const loadSave = async () => {
     await applyFilters();
 };
 
-// Add the onclick event for the Load Save button here.
+// Open a database folder.
+const openDatabase = async () => {
+    let success: boolean;
+    try {
+        success = await invoke("load_database");
+    }
+    catch (e) {
+        await message(e as string, { title: "Opening the Database Failed", kind: "error" });
+        return;
+    }
+
+    if (!success) { return; }
+
+    await createPlayerView();
+    await applyFilters();
+};
+
+// Add the onclick events for the Load Save and Open Database buttons here.
 const enableLoadButton = () => {
-    const button = document.getElementsByTagName("button")[0];
-    button.onclick = loadSave;
+    const buttons = document.getElementsByTagName("button");
+    buttons[0].onclick = loadSave;
+    buttons[1].onclick = openDatabase;
 };
 
 enableLoadButton();
\ No newline at end of file
@@ -1,11 +1,11 @@
 // Paging-related code.
-import { overwriteTable, PLAYERS } from "./table";
+import { fetchPage, TOTAL_PLAYERS } from "./table";
 
 export let PAGE = 0;
 export const ROWS_PER_PAGE = 50;
 
 const getPageCount = (): number => {
-    return Math.ceil(PLAYERS.length / ROWS_PER_PAGE);
+    return Math.ceil(TOTAL_PLAYERS / ROWS_PER_PAGE);
 };
 
 const updatePagingText = () => {
@@ -34,28 +34,22 @@ const togglePrevPageButton = () => {
     }
 };
 
-const toNextPage = () => {
+const toNextPage = async () => {
     PAGE++;
-    overwriteTable();
-
-    const button = document.getElementById("prev-page") as HTMLButtonElement;
-    button.disabled = false;
-    toggleNextPageButton();
-    updatePagingText();
+    await fetchPage();
 };
 
-const toPrevPage = () => {
+const toPrevPage = async () => {
     PAGE--;
-    overwriteTable();
+    await fetchPage();
+};
 
-    const button = document.getElementById("next-page") as HTMLButtonElement;
-    button.disabled = false;
-    togglePrevPageButton();
-    updatePagingText();
+export const setPage = (page: number) => {
+    PAGE = page;
 };
 
-export const initialisePaging = () => {
-    PAGE = 0;
+// Update the page numbers and buttons after a page has been fetched.
+export const updatePaging = () => {
     updatePagingText();
     toggleNextPageButton();
     togglePrevPageButton();
@@ -65,4 +59,4 @@ export const initialisePaging = () => {
 
     const prevButton = document.getElementById("prev-page") as HTMLButtonElement;
     prevButton.onclick = toPrevPage;
-};
\ No newline at end of file
+};
@@ -1,15 +1,31 @@
 import { invoke } from "@tauri-apps/api/core";
-import { initialisePaging, PAGE, ROWS_PER_PAGE } from "./paging";
+import type { PlayerFilters } from "./filter";
+import { PAGE, ROWS_PER_PAGE, setPage, updatePaging } from "./paging";
 
 type Player = {
+    id: number,
     forename: string,
     surname: string,
     dateOfBirth: [number, number, number],
     positions: number[],
-    columns: [string | number],
+    columns: [string | number | null],
 };
 
-export let PLAYERS: Player[] = [];
+type PlayerPage = {
+    players: Player[],
+    total: number,
+};
+
+type SortKey = {
+    column: string,
+    ascending: boolean,
+};
+
+// The players on the current page.
+let PLAYERS: Player[] = [];
+// The number of players on every page.
+export let TOTAL_PLAYERS = 0;
+export let DISPLAY_CURRENCY: number | null = null;
 export const HEADERS = [
     "Name",
     "Random",
@@ -27,6 +43,11 @@ export const HEADERS = [
     "RW Rating",
     "Club Contracted",
     "Club Playing",
+    "Estimated Wage",
+    "Weekly Wage",
+    "Estimated Value",
+    "Club Cash",
+    "Club Budget",
     "Adaptability",
     "Ambition",
     "Determination",
@@ -89,62 +110,60 @@ export const HEADERS = [
     "Wristshot",
 ];
 
-// Get the players from the database.
-export const fetchPlayers = async (
-    birthYears: [number, number],
-    nationalTeamCheck: boolean | undefined,
-    secondNationalityCheck: boolean | undefined,
-    declaredCheck: boolean | undefined,
-    includeNationalities: number[],
-    excludeNationalities: number[],
-    includeClubsContracted: number[],
-    excludeClubsContracted: number[],
-    includeClubsPlaying: number[],
-    excludeClubsPlaying: number[],
-    includeCompsContracted: number[],
-    excludeCompsContracted: number[],
-    includeCompsPlaying: number[],
-    excludeCompsPlaying: number[],
-    includeNationsContracted: number[],
-    excludeNationsContracted: number[],
-    includeNationsPlaying: number[],
-    excludeNationsPlaying: number[],
-) => {
-    invoke("fetch_players", {
+// The columns the players are sorted by, in order.
+let SORT_KEYS: SortKey[] = [];
+let LAST_FILTERS: PlayerFilters;
+
+// Get the first page of the players from the database.
+export const fetchPlayers = async (filters: PlayerFilters) => {
+    LAST_FILTERS = filters;
+    setPage(0);
+    await fetchPage();
+};
+
+// Get the players on the current page.
+export const fetchPage = async () => {
+    const page = await invoke("fetch_players", {
         "headers": HEADERS,
-        "birthYears": birthYears,
-        "nationalTeamCheck": nationalTeamCheck,
-        "secondNationalityCheck": secondNationalityCheck,
-        "declaredCheck": declaredCheck,
-        "includeNationalities": includeNationalities,
-        "excludeNationalities": excludeNationalities,
-        "includeClubsContracted": includeClubsContracted,
-        "excludeClubsContracted": excludeClubsContracted,
-        "includeClubsPlaying": includeClubsPlaying,
-        "excludeClubsPlaying": excludeClubsPlaying,
-        "includeCompsContracted": includeCompsContracted,
-        "excludeCompsContracted": excludeCompsContracted,
-        "includeCompsPlaying": includeCompsPlaying,
-        "excludeCompsPlaying": excludeCompsPlaying,
-        "includeNationsContracted": includeNationsContracted,
-        "excludeNationsContracted": excludeNationsContracted,
-        "includeNationsPlaying": includeNationsPlaying,
-        "excludeNationsPlaying": excludeNationsPlaying,
-    }).then((players) => {
-        PLAYERS = players as Player[];
-        initialisePaging();
-        overwriteTable();
-    });
+        "filters": LAST_FILTERS,
+        "displayCurrency": DISPLAY_CURRENCY,
+        "sort": SORT_KEYS,
+        "offset": PAGE * ROWS_PER_PAGE,
+        "limit": ROWS_PER_PAGE,
+    }) as PlayerPage;
+
+    PLAYERS = page.players;
+    TOTAL_PLAYERS = page.total;
+    updatePaging();
+    overwriteTable();
+};
+
+// Get the players on every page, e.g. for exporting.
+export const fetchAllPlayers = async (): Promise<Player[]> => {
+    const page = await invoke("fetch_players", {
+        "headers": HEADERS,
+        "filters": LAST_FILTERS,
+        "displayCurrency": DISPLAY_CURRENCY,
+        "sort": SORT_KEYS,
+        "offset": 0,
+        "limit": null,
+    }) as PlayerPage;
+
+    return page.players;
+};
+
+// Set the currency money is shown in, or null for the currency of each player's club.
+export const setDisplayCurrency = (currency: number | null) => {
+    DISPLAY_CURRENCY = currency;
 };
 
 // Overwrite existing data in the player table without removing elements.
 export const overwriteTable = () => {
     const tbody = document.getElementById("players") as HTMLTableSectionElement;
     let counter = 0;
-    let i = PAGE * ROWS_PER_PAGE;
 
-    while (counter < ROWS_PER_PAGE && i < PLAYERS.length) {
-        const player = PLAYERS[i]
+    while (counter < PLAYERS.length) {
+        const player = PLAYERS[counter];
         let tr: HTMLTableRowElement;
 
         // Use an existing row if one exists.
@@ -167,7 +186,6 @@ export const overwriteTable = () => {
         }
 
         counter++;
-        i++;
     }
 
     // Remove possible unused rows.
@@ -177,7 +195,12 @@ export const overwriteTable = () => {
 };
 
 // Get the value as a displayable one.
-const getDisplayValue = (index: number, value: string | number): string => {
+const getDisplayValue = (index: number, value: string | number | null): string => {
+    // An attribute the chart can not convert.
+    if (value === null) {
+        return "";
+    }
+
     const headerName = HEADERS[index];
 
     if (headerName === "GK Rating"
@@ -197,149 +220,47 @@ const getDisplayValue = (index: number, value: string | number): string => {
     return value.toString();
 };
 
-// Sort the table.
-export const sortTable = (n: number) => {
-    let sortAscending = 1;
-    const before = JSON.stringify(PLAYERS);
-
-    const columnName = (document.getElementById("headers") as HTMLTableRowElement).children[n].textContent;
+// Sort the table by a column, or the other way if it already is. With addKey, sort by the column after the columns
+// the table is already sorted by.
+export const sortTable = async (n: number, addKey: boolean) => {
+    const column = HEADERS[n];
+    const existing = SORT_KEYS.find((key) => key.column === column);
 
-    do {
-        if (columnName === "Name") {
-            sortName(sortAscending);
+    if (addKey) {
+        if (existing === undefined) {
+            SORT_KEYS.push({ column, ascending: true });
         }
-
-        else if (columnName === "Position") {
-            sortPosition(sortAscending);
-        }
-
-        else if (columnName === "Birthday" || columnName === "Age") {
-            sortBirthday(sortAscending);
-        }
-
-        else if (
-            columnName === "GK Rating" ||
-            columnName === "LD Rating" ||
-            columnName === "RD Rating" ||
-            columnName === "LW Rating" ||
-            columnName === "C Rating" ||
-            columnName === "RW Rating"
-        ) {
-            sortGeneric(sortAscending, n, -1.0);
-        }
-
         else {
-            sortGeneric(sortAscending, n, "");
-        }
-
-        // Check if anything changed.
-        if (before !== JSON.stringify(PLAYERS)) {
-            overwriteTable();
-            break;
-        }
-
-        sortAscending *= -1;
-    } while (sortAscending !== 1);
-};
-
-// Sort by the player name.
-const sortName = (sortAscending: number) => {
-    PLAYERS.sort((a, b) => {
-        if (a.surname < b.surname) {
-            return -1 * sortAscending;
-        }
-
-        if (b.surname < a.surname) {
-            return 1 * sortAscending;
-        }
-
-        if (a.forename < b.forename) {
-            return -1 * sortAscending;
+            existing.ascending = !existing.ascending;
         }
+    }
 
-        if (b.forename < a.forename) {
-            return 1 * sortAscending;
-        }
+    else {
+        const ascending = SORT_KEYS.length === 1 && existing !== undefined ? !existing.ascending : true;
+        SORT_KEYS = [{ column, ascending }];
+    }
 
-        return 0;
-    });
+    updateSortIndicators();
+    setPage(0);
+    await fetchPage();
 };
 
-// Sort by the player position.
-const sortPosition = (sortAscending: number) => {
-    PLAYERS.sort((a, b) => {
-        for (let i = 0; i < 6; i++) {
-            const a_pos = a.positions[i];
-            const b_pos = b.positions[i];
-
-            if (a_pos === undefined && b_pos === undefined) {
-                break;
-            }
-
-            if (a_pos === b_pos) {
-                continue;
-            }
-
-            if (a_pos === undefined && b_pos !== undefined) {
-                return -1 * sortAscending;
-            }
-
-            if (b_pos === undefined && a_pos !== undefined) {
-                return 1 * sortAscending;
-            }
-
-            return (a_pos - b_pos) * sortAscending;
-        }
-
-        return 0;
-    });
+// Forget the sorting, e.g. when the columns change.
+export const clearSorting = () => {
+    SORT_KEYS = [];
+    updateSortIndicators();
 };
 
-const sortBirthday = (sortAscending: number) => {
-    PLAYERS.sort((a, b) => {
-        let diff = a.dateOfBirth[0] - b.dateOfBirth[0];
-        if (diff !== 0) {
-            return diff * sortAscending;
-        }
-
-        diff = a.dateOfBirth[1] - b.dateOfBirth[1];
-        if (diff !== 0) {
-            return diff * sortAscending;
-        }
+// Show the sorting direction after the names of the sorted columns.
+const updateSortIndicators = () => {
+    const tr = document.getElementById("headers") as HTMLTableRowElement;
 
-        return (a.dateOfBirth[2] - b.dateOfBirth[2]) * sortAscending;
-    });
+    for (const [i, th] of Array.from(tr.children).entries()) {
+        const key = SORT_KEYS.find((key) => key.column === HEADERS[i]);
+        th.textContent = key === undefined ? HEADERS[i] : `${HEADERS[i]} ${key.ascending ? "▲" : "▼"}`;
+    }
 };
 
-// The generic sorting.
-const sortGeneric = (sortAscending: number, n: number, emptyColumn: string | number) => {
-    PLAYERS.sort((a, b) => {
-        const aCol = a.columns[n];
-        const bCol = b.columns[n];
-
-        if (aCol === bCol) {
-            return 0;
-        }
-
-        // Make the empty columns always show up last.
-        if (aCol === emptyColumn) {
-            return 1;
-        }
-
-        if (bCol === emptyColumn) {
-            return -1;
-        }
-
-        if (aCol < bCol) {
-            return -1 * sortAscending;
-        }
-
-        // if (bCol < aCol) {
-            return 1 * sortAscending;
-        // }
-    });
-}
-
 const createCell = (content: string): HTMLTableCellElement => {
     const td = document.createElement("td");
     td.textContent = content;